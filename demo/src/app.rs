@@ -54,8 +54,15 @@ pub enum Color {
 
     #[enum2str("Tuple Vec")]
     TupleVec(Vec<(u8, u32)>),
+
+    #[enum2egui(hidden)]
+    Unset,
 }
 
+#[derive(Gui, Clone, Copy, Default, serde::Deserialize, serde::Serialize, PartialEq)]
+#[enum2egui(label = "Marker (unit struct)")]
+pub struct Marker;
+
 #[derive(Gui, Clone, serde::Deserialize, serde::Serialize)]
 pub struct Data {
     #[enum2egui(skip)]
@@ -65,11 +72,30 @@ pub struct Data {
     a: String,
 
     string: String,
+
+    #[enum2egui(multiline, rows = 3, hint = "Describe the scene")]
+    description: String,
+
+    #[enum2egui(password, max_chars = 32)]
+    api_key: String,
+
+    #[enum2egui(code, language = "wgsl")]
+    shader: String,
+
     i8: i8,
     i16: i16,
     i32: i32,
     i64: i64,
     i128: i128,
+    isize: isize,
+    u64: u64,
+    char: char,
+    #[serde(skip)]
+    name: std::sync::Arc<str>,
+    #[serde(skip)]
+    shared: std::sync::Arc<std::sync::Mutex<SubData>>,
+    #[serde(skip)]
+    shared_cell: std::rc::Rc<std::cell::RefCell<Metadata>>,
     bool: bool,
     u8: u8,
     u16: u16,
@@ -81,10 +107,84 @@ pub struct Data {
     nested_struct: SubData,
     unnamed_struct: TupleStruct,
     primary_color: Color,
+    #[enum2egui(picker = "search")]
     secondary_color: Color,
+    legacy_color: Color,
+    marker: Marker,
+    access: Permission,
+    shape: Shape,
+    opcode: Opcode,
     optional: Option<SubData>,
+
+    #[enum2egui(option_style = "button")]
+    optional_button: Option<Metadata>,
+
+    result: Result<u32, String>,
     list: Vec<Color>,
 
+    #[enum2egui(min_len = 1, max_len = 4, new_item = "Waypoint::origin")]
+    waypoints: Vec<Waypoint>,
+
+    position: [f32; 3],
+
+    #[enum2egui(color)]
+    albedo: [u8; 3],
+
+    #[enum2egui(color, alpha)]
+    tint: [f32; 4],
+
+    #[enum2egui(color, hsva)]
+    highlight: [f32; 3],
+
+    #[enum2egui(color, alpha)]
+    packed_color: u32,
+
+    translation: glam::Vec3,
+    rotation: glam::Quat,
+    model: glam::Mat4,
+
+    release_date: chrono::NaiveDate,
+    last_login: chrono::DateTime<chrono::Utc>,
+
+    settings: serde_json::Value,
+    scores: enum2egui::SerdeInspect<std::collections::BTreeMap<String, u32>>,
+
+    #[enum2egui(with = "RigidBodyDef")]
+    body: physics::RigidBody,
+
+    components: indexmap::IndexMap<String, f32>,
+
+    #[enum2egui(flags)]
+    permissions: Vec<Permission>,
+    slots: arrayvec::ArrayVec<u8, 4>,
+
+    #[serde(skip)]
+    background: egui::Color32,
+    #[serde(skip)]
+    outline: egui::Stroke,
+    #[serde(skip)]
+    offset: egui::Vec2,
+
+    #[enum2egui(matrix)]
+    transform: [[f32; 4]; 4],
+
+    #[enum2egui(parse)]
+    server: std::net::SocketAddr,
+    timeout: std::time::Duration,
+    path: std::path::PathBuf,
+    range: std::ops::Range<u32>,
+    inclusive_range: std::ops::RangeInclusive<f32>,
+    non_zero: std::num::NonZeroU32,
+
+    expression: Expr,
+    tree: Node,
+
+    deque: std::collections::VecDeque<u32>,
+    tags: std::collections::BTreeSet<String>,
+    ids: std::collections::HashSet<u32>,
+    linked_list: std::collections::LinkedList<String>,
+    heap: std::collections::BinaryHeap<u32>,
+
     hashmap: std::collections::HashMap<String, Metadata>,
     btreemap: std::collections::BTreeMap<String, Metadata>,
     hashbrown_map: hashbrown::HashMap<String, Metadata>,
@@ -94,15 +194,31 @@ impl Default for Data {
     fn default() -> Self {
         Self {
             hashmap: HashMap::default(),
+            deque: [1, 2, 3].into(),
+            tags: ["alpha".to_string(), "beta".to_string()].into(),
+            ids: [7, 3, 5].into(),
+            linked_list: ["first".to_string()].into(),
+            heap: [4, 1, 9].into(),
             btreemap: BTreeMap::default(),
             hashbrown_map: hashbrown::HashMap::default(),
             skipped_data: 0,
             string: "Hello!".to_string(),
+            description: String::new(),
+            api_key: "hunter2".to_string(),
+            shader:
+                "@fragment\nfn main() -> @location(0) vec4<f32> {\n    return vec4<f32>(1.0);\n}"
+                    .to_string(),
             i8: 42,
             i16: 1555,
             i32: -242522,
             i64: 23425259,
             i128: i128::MAX,
+            isize: isize::MIN,
+            u64: u64::MAX,
+            char: 'x',
+            name: "Read-only shared string".into(),
+            shared: Default::default(),
+            shared_cell: Default::default(),
             bool: true,
             u8: 94,
             u16: 14029,
@@ -115,7 +231,14 @@ impl Default for Data {
             unnamed_struct: TupleStruct::default(),
             primary_color: Color::default(),
             secondary_color: Color::default(),
+            legacy_color: Color::SkippedCustom(12, 34, 56),
+            marker: Marker,
+            access: Permission::default(),
+            shape: Shape::default(),
+            opcode: Opcode::default(),
             optional: Some(SubData::default()),
+            optional_button: None,
+            result: Err("Not loaded".to_string()),
             list: vec![
                 Color::Red,
                 Color::Green,
@@ -129,6 +252,52 @@ impl Default for Data {
                     },
                 },
             ],
+            waypoints: vec![Waypoint::origin()],
+            position: [1.0, 2.0, 3.0],
+            albedo: [200, 120, 40],
+            tint: [0.2, 0.4, 0.8, 0.5],
+            highlight: [0.15, 0.8, 1.0],
+            packed_color: 0xFF8800FF,
+            translation: glam::Vec3::new(0.0, 1.0, -5.0),
+            rotation: glam::Quat::from_rotation_y(std::f32::consts::FRAC_PI_4),
+            model: glam::Mat4::IDENTITY,
+            release_date: chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap_or_default(),
+            last_login: chrono::Utc::now(),
+            settings: serde_json::json!({
+                "name": "enum2egui",
+                "version": 1,
+                "features": ["derive", "inspect"],
+                "window": { "width": 1280, "height": 720, "fullscreen": false },
+                "theme": null,
+            }),
+            scores: enum2egui::SerdeInspect(
+                [("alice".to_string(), 120), ("bob".to_string(), 95)].into(),
+            ),
+            body: physics::RigidBody {
+                mass: 2.5,
+                velocity: [0.0, -9.8, 0.0],
+                kind: physics::BodyKind::Dynamic { sleeping: false },
+            },
+            components: [("health".to_string(), 100.0), ("armor".to_string(), 25.0)].into(),
+            slots: [1, 2].into_iter().collect(),
+            permissions: vec![Permission::Read],
+            background: egui::Color32::DARK_GRAY,
+            outline: egui::Stroke::new(1.0, egui::Color32::WHITE),
+            offset: egui::Vec2::new(4.0, 8.0),
+            server: std::net::SocketAddr::from(([127, 0, 0, 1], 8080)),
+            timeout: std::time::Duration::from_millis(1500),
+            path: std::path::PathBuf::from("assets/scene.json"),
+            range: 10..20,
+            inclusive_range: 0.0..=1.0,
+            non_zero: std::num::NonZeroU32::MIN,
+            expression: Expr::Add(Box::new(Expr::Num(1.0)), Box::new(Expr::Num(2.0))),
+            tree: Node::default(),
+            transform: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
             a: "".to_string(),
         }
     }
@@ -155,6 +324,128 @@ pub struct SubData {
     list: Vec<Metadata>,
 }
 
+#[derive(
+    Gui, EnumStr, Debug, Clone, Copy, Default, serde::Deserialize, serde::Serialize, PartialEq,
+)]
+pub enum Permission {
+    #[default]
+    Read,
+    Write,
+    Execute,
+}
+
+#[derive(Gui, EnumStr, Debug, Clone, Default, serde::Deserialize, serde::Serialize, PartialEq)]
+pub enum Shape {
+    /// A single point with no extent.
+    #[default]
+    Point,
+
+    /// A circle around the origin.
+    #[enum2egui(group = "Primitives")]
+    Circle { radius: f32 },
+
+    #[enum2egui(group = "Primitives", tooltip = "An axis-aligned rectangle")]
+    Rect { width: f32, height: f32 },
+
+    /// A polyline through the given points.
+    #[enum2egui(group = "Paths")]
+    #[enum2str("Polyline")]
+    Polyline(Vec<[f32; 2]>),
+
+    /// A cubic Bezier curve.
+    #[enum2egui(group = "Paths")]
+    #[enum2str("Bezier")]
+    Bezier([[f32; 2]; 4]),
+}
+
+#[derive(
+    Gui, EnumStr, Debug, Clone, Copy, Default, serde::Deserialize, serde::Serialize, PartialEq,
+)]
+#[enum2egui(show_discriminant)]
+#[repr(u8)]
+pub enum Opcode {
+    #[default]
+    Ping = 1,
+    Pong,
+    Data = 8,
+    Close = 0xFF,
+}
+
+/// Stands in for types from another crate, which can't derive `Gui`.
+mod physics {
+    #[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, PartialEq)]
+    pub struct RigidBody {
+        pub mass: f32,
+        pub velocity: [f32; 3],
+        pub kind: BodyKind,
+    }
+
+    #[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, PartialEq)]
+    pub enum BodyKind {
+        #[default]
+        Static,
+        Kinematic,
+        Dynamic {
+            sleeping: bool,
+        },
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Gui)]
+#[enum2egui(remote = "physics::RigidBody")]
+struct RigidBodyDef {
+    mass: f32,
+    velocity: [f32; 3],
+    #[enum2egui(with = "BodyKindDef")]
+    kind: physics::BodyKind,
+}
+
+#[allow(dead_code)]
+#[derive(Gui)]
+#[enum2egui(remote = "physics::BodyKind")]
+enum BodyKindDef {
+    Static,
+    Kinematic,
+    Dynamic { sleeping: bool },
+}
+
+#[derive(Gui, EnumStr, Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq)]
+pub enum Expr {
+    #[enum2str("Number")]
+    Num(f64),
+
+    #[enum2str("Add")]
+    Add(Box<Expr>, Box<Expr>),
+
+    #[enum2str("Multiply")]
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Default for Expr {
+    fn default() -> Self {
+        Self::Num(0.0)
+    }
+}
+
+#[derive(Gui, Clone, Default, serde::Deserialize, serde::Serialize, PartialEq)]
+pub struct Node {
+    name: String,
+    children: Vec<Node>,
+}
+
+#[derive(Gui, Clone, serde::Deserialize, serde::Serialize, PartialEq)]
+pub struct Waypoint {
+    x: f32,
+    y: f32,
+}
+
+impl Waypoint {
+    fn origin() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+}
+
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 #[derive(Default)]
@@ -169,6 +460,7 @@ impl eframe::App for DemoApp {
             .id_salt(ui.next_auto_id())
             .show(ui, |ui| {
                 data.ui_mut(ui);
+                ui.collapsing("Style", enum2egui::global_style_ui_mut);
             });
     }
 }
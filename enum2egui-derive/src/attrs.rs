@@ -0,0 +1,638 @@
+//! Typed model of the `#[enum2egui(...)]` attributes.
+//!
+//! Every attribute is parsed up front, so that misspelled keys, values of the wrong
+//! type and keys written where they have no effect are reported at the offending token
+//! instead of being silently ignored.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Attribute, Data, Field, Fields, Ident, Lit, Meta, NestedMeta, Type, Variant};
+
+/// Where an attribute is written, which decides the keys it accepts.
+#[derive(Clone, Copy)]
+pub(crate) enum Position {
+    Struct,
+    Enum,
+    Variant,
+    Field,
+}
+
+impl Position {
+    fn keys(self) -> &'static [&'static str] {
+        match self {
+            Self::Struct => &["label", "remote"],
+            Self::Enum => &["remote", "picker", "show_discriminant"],
+            Self::Variant => &["skip", "hidden", "label", "group", "tooltip"],
+            Self::Field => &[
+                "skip",
+                "label",
+                "with",
+                "flags",
+                "matrix",
+                "parse",
+                "color",
+                "alpha",
+                "hsva",
+                "picker",
+                "option_style",
+                "min_len",
+                "max_len",
+                "new_item",
+                "multiline",
+                "password",
+                "code",
+                "rows",
+                "max_chars",
+                "hint",
+                "language",
+            ],
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Self::Struct => "structs",
+            Self::Enum => "enums",
+            Self::Variant => "enum variants",
+            Self::Field => "fields",
+        }
+    }
+}
+
+/// The variant selector of a derived enum, from `picker = "..."`.
+#[derive(Clone, Copy)]
+pub(crate) enum Picker {
+    Combo,
+    Radio,
+    Tabs,
+    Search,
+}
+
+impl ToTokens for Picker {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Combo => quote! { enum2egui::Picker::Combo },
+            Self::Radio => quote! { enum2egui::Picker::Radio },
+            Self::Tabs => quote! { enum2egui::Picker::Tabs },
+            Self::Search => quote! { enum2egui::Picker::Search },
+        });
+    }
+}
+
+/// How an `Option` field is toggled, from `option_style = "..."`.
+#[derive(Clone, Copy)]
+pub(crate) enum OptionStyle {
+    Checkbox,
+    Button,
+}
+
+impl ToTokens for OptionStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Checkbox => quote! { enum2egui::OptionStyle::Checkbox },
+            Self::Button => quote! { enum2egui::OptionStyle::Button },
+        });
+    }
+}
+
+/// Attributes on the deriving struct or enum.
+#[derive(Default)]
+pub(crate) struct ContainerAttrs {
+    pub label: Option<String>,
+    pub remote: Option<syn::Path>,
+    pub picker: Option<Picker>,
+    pub show_discriminant: bool,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute], position: Position) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+        let mut parsed = Self::default();
+        for entry in &entries(attrs, position, &mut errors) {
+            let result = match entry.key() {
+                "label" => entry.string().map(|value| parsed.label = Some(value)),
+                "remote" => entry.path().map(|value| parsed.remote = Some(value)),
+                "picker" => entry.picker().map(|value| parsed.picker = Some(value)),
+                "show_discriminant" => entry.flag().map(|()| parsed.show_discriminant = true),
+                _ => unreachable!("keys are checked by `entries`"),
+            };
+            errors.extend(result);
+        }
+        errors.finish(parsed)
+    }
+}
+
+/// Attributes on a variant of the deriving enum.
+#[derive(Default)]
+pub(crate) struct VariantAttrs {
+    pub skip: bool,
+    pub hidden: bool,
+    pub label: Option<String>,
+    pub group: Option<String>,
+    pub tooltip: Option<String>,
+}
+
+impl VariantAttrs {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+        let mut parsed = Self::default();
+        let entries = entries(attrs, Position::Variant, &mut errors);
+        for entry in &entries {
+            let result = match entry.key() {
+                "skip" => entry.flag().map(|()| parsed.skip = true),
+                "hidden" => entry.flag().map(|()| parsed.hidden = true),
+                "label" => entry.string().map(|value| parsed.label = Some(value)),
+                "group" => entry.string().map(|value| parsed.group = Some(value)),
+                "tooltip" => entry.string().map(|value| parsed.tooltip = Some(value)),
+                _ => unreachable!("keys are checked by `entries`"),
+            };
+            errors.extend(result);
+        }
+        if parsed.skip
+            && let Some(hidden) = find(&entries, &["hidden"])
+        {
+            errors.push(hidden.error("`hidden` can't be combined with `skip`"));
+        }
+        errors.finish(parsed)
+    }
+}
+
+/// Attributes on a field of the deriving struct, or of one of its variants.
+#[derive(Default)]
+pub(crate) struct FieldAttrs {
+    pub skip: bool,
+    pub label: Option<String>,
+    /// Usually a remote derive's mirror type, whose `ui` and `ui_mut` functions
+    /// render the field in place of `GuiInspect`.
+    pub with: Option<syn::Path>,
+    pub flags: bool,
+    pub matrix: bool,
+    pub parse: bool,
+    pub color: bool,
+    pub alpha: bool,
+    pub hsva: bool,
+    pub picker: Option<Picker>,
+    pub option_style: Option<OptionStyle>,
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+    pub new_item: Option<syn::Path>,
+    pub multiline: bool,
+    pub password: bool,
+    pub code: bool,
+    pub rows: Option<usize>,
+    pub max_chars: Option<usize>,
+    pub hint: Option<String>,
+    pub language: Option<String>,
+}
+
+const VEC_KEYS: &[&str] = &["min_len", "max_len", "new_item"];
+
+const TEXT_KEYS: &[&str] = &[
+    "multiline",
+    "password",
+    "code",
+    "rows",
+    "max_chars",
+    "hint",
+    "language",
+];
+
+/// Keys that each select a different widget for a field, so at most one group may be used.
+const WIDGET_KEYS: &[&[&str]] = &[
+    &["with"],
+    &["flags"],
+    &["matrix"],
+    &["parse"],
+    &["color", "alpha", "hsva"],
+    &["option_style"],
+    VEC_KEYS,
+    TEXT_KEYS,
+];
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> syn::Result<Self> {
+        let mut errors = Errors::default();
+        let mut parsed = Self::default();
+        let entries = entries(&field.attrs, Position::Field, &mut errors);
+        for entry in &entries {
+            let result = match entry.key() {
+                "skip" => entry.flag().map(|()| parsed.skip = true),
+                "label" => entry.string().map(|value| parsed.label = Some(value)),
+                "with" => entry.path().map(|value| parsed.with = Some(value)),
+                "flags" => entry.flag().map(|()| parsed.flags = true),
+                "matrix" => entry.flag().map(|()| parsed.matrix = true),
+                "parse" => entry.flag().map(|()| parsed.parse = true),
+                "color" => entry.flag().map(|()| parsed.color = true),
+                "alpha" => entry.flag().map(|()| parsed.alpha = true),
+                "hsva" => entry.flag().map(|()| parsed.hsva = true),
+                "picker" => entry.picker().map(|value| parsed.picker = Some(value)),
+                "option_style" => entry
+                    .option_style()
+                    .map(|value| parsed.option_style = Some(value)),
+                "min_len" => entry.usize().map(|value| parsed.min_len = Some(value)),
+                "max_len" => entry.usize().map(|value| parsed.max_len = Some(value)),
+                "new_item" => entry.path().map(|value| parsed.new_item = Some(value)),
+                "multiline" => entry.flag().map(|()| parsed.multiline = true),
+                "password" => entry.flag().map(|()| parsed.password = true),
+                "code" => entry.flag().map(|()| parsed.code = true),
+                "rows" => entry.usize().map(|value| parsed.rows = Some(value)),
+                "max_chars" => entry.usize().map(|value| parsed.max_chars = Some(value)),
+                "hint" => entry.string().map(|value| parsed.hint = Some(value)),
+                "language" => entry.string().map(|value| parsed.language = Some(value)),
+                _ => unreachable!("keys are checked by `entries`"),
+            };
+            errors.extend(result);
+        }
+        check_field(field, &entries, &mut errors);
+        if let (Some(min_len), Some(max_len)) = (parsed.min_len, parsed.max_len)
+            && min_len > max_len
+            && let Some(entry) = find(&entries, &["max_len"])
+        {
+            errors.push(entry.error(format!(
+                "`max_len = {max_len}` is less than `min_len = {min_len}`"
+            )));
+        }
+        errors.finish(parsed)
+    }
+
+    pub fn has_vec_options(&self) -> bool {
+        self.min_len.is_some() || self.max_len.is_some() || self.new_item.is_some()
+    }
+
+    pub fn has_text_options(&self) -> bool {
+        self.multiline
+            || self.password
+            || self.code
+            || self.rows.is_some()
+            || self.max_chars.is_some()
+            || self.hint.is_some()
+            || self.language.is_some()
+    }
+}
+
+/// Rejects field attributes that conflict with each other or can't apply to the field's type.
+/// Types are only checked by name, so a field whose type is an alias is rejected too.
+fn check_field(field: &Field, entries: &[Entry], errors: &mut Errors) {
+    let mut widgets = WIDGET_KEYS.iter().filter_map(|group| find(entries, group));
+    if let Some(first) = widgets.next() {
+        widgets.for_each(|other| {
+            errors.push(other.error(format!(
+                "`{}` can't be combined with `{}`",
+                other.key(),
+                first.key()
+            )));
+        });
+    }
+
+    if let Some(entry) = find(entries, &["alpha", "hsva"])
+        && find(entries, &["color"]).is_none()
+    {
+        errors.push(entry.error(format!("`{}` requires `color`", entry.key())));
+    }
+
+    let ty = &field.ty;
+    let expectations: [(&[&str], bool, &str); 6] = [
+        (TEXT_KEYS, is_path_to(ty, "String"), "`String` fields"),
+        (VEC_KEYS, is_path_to(ty, "Vec"), "`Vec` fields"),
+        (
+            &["flags"],
+            is_path_to(ty, "Vec") || is_path_to(ty, "HashSet"),
+            "`Vec` and `HashSet` fields",
+        ),
+        (
+            &["color"],
+            is_color(ty),
+            "`[u8; 3]`, `[u8; 4]`, `[f32; 3]`, `[f32; 4]`, `(u8, u8, u8)`, `(u8, u8, u8, u8)` and `u32` fields",
+        ),
+        (
+            &["option_style"],
+            is_path_to(ty, "Option"),
+            "`Option` fields",
+        ),
+        (
+            &["matrix"],
+            is_nested_array(ty),
+            "nested array fields like `[[f32; 4]; 4]`",
+        ),
+    ];
+    expectations
+        .into_iter()
+        .filter(|(_, applies, _)| !applies && !is_opaque(ty))
+        .filter_map(|(keys, _, expected)| Some((find(entries, keys)?, expected)))
+        .for_each(|(entry, expected)| {
+            errors.push(entry.error(format!("`{}` only applies to {expected}", entry.key())));
+        });
+}
+
+/// Whether `ty` is the standard type `name`, written either bare, like `String`,
+/// or as a path from `std`, `alloc` or `core`, like `std::string::String`.
+pub(crate) fn is_path_to(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => {
+            let segments = &path.path.segments;
+            let is_std = |segment: &syn::PathSegment| {
+                ["std", "alloc", "core"]
+                    .iter()
+                    .any(|root| segment.ident == root)
+            };
+            let rooted = segments.len() == 1 && path.path.leading_colon.is_none()
+                || segments.first().is_some_and(is_std);
+            rooted && segments.last().is_some_and(|segment| segment.ident == name)
+        }
+        Type::Group(group) => is_path_to(&group.elem, name),
+        _ => false,
+    }
+}
+
+/// Whether `ty` is one of the shapes that implement `ColorComponents`.
+fn is_color(ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => {
+            let len = match &array.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: Lit::Int(len), ..
+                }) => len.base10_parse::<usize>().ok(),
+                _ => None,
+            };
+            matches!(len, Some(3 | 4))
+                && (is_path_to(&array.elem, "u8") || is_path_to(&array.elem, "f32"))
+        }
+        Type::Tuple(tuple) => {
+            matches!(tuple.elems.len(), 3 | 4)
+                && tuple.elems.iter().all(|elem| is_path_to(elem, "u8"))
+        }
+        Type::Group(group) => is_color(&group.elem),
+        ty => is_path_to(ty, "u32"),
+    }
+}
+
+fn is_nested_array(ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => matches!(*array.elem, Type::Array(_)),
+        Type::Group(group) => is_nested_array(&group.elem),
+        _ => false,
+    }
+}
+
+/// Types that can't be judged by name, such as `Self::Item` or types from macros.
+fn is_opaque(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.qself.is_some(),
+        Type::Macro(_) | Type::Verbatim(_) | Type::Infer(_) => true,
+        Type::Group(group) => is_opaque(&group.elem),
+        _ => false,
+    }
+}
+
+/// A field with its parsed attributes.
+pub(crate) struct ParsedField<'a> {
+    pub field: &'a Field,
+    pub attrs: FieldAttrs,
+}
+
+/// A variant with its parsed attributes and those of its fields.
+pub(crate) struct ParsedVariant<'a> {
+    pub variant: &'a Variant,
+    pub attrs: VariantAttrs,
+    pub fields: Vec<ParsedField<'a>>,
+}
+
+pub(crate) fn parse_fields(fields: &Fields) -> syn::Result<Vec<ParsedField<'_>>> {
+    collect(fields.iter().map(|field| {
+        Ok(ParsedField {
+            field,
+            attrs: FieldAttrs::parse(field)?,
+        })
+    }))
+}
+
+pub(crate) fn parse_variants<'a>(
+    variants: impl Iterator<Item = &'a Variant>,
+) -> syn::Result<Vec<ParsedVariant<'a>>> {
+    collect(variants.map(|variant| {
+        let mut errors = Errors::default();
+        let attrs = VariantAttrs::parse(&variant.attrs).unwrap_or_else(|error| {
+            errors.push(error);
+            VariantAttrs::default()
+        });
+        let fields = parse_fields(&variant.fields).unwrap_or_else(|error| {
+            errors.push(error);
+            Vec::new()
+        });
+        errors.finish(ParsedVariant {
+            variant,
+            attrs,
+            fields,
+        })
+    }))
+}
+
+/// Parses the attributes of every field and variant in `data`, so that their errors
+/// can be reported alongside those of the container.
+pub(crate) fn check_data(data: &Data) -> syn::Result<()> {
+    match data {
+        Data::Struct(data) => parse_fields(&data.fields).map(drop),
+        Data::Enum(data) => parse_variants(data.variants.iter()).map(drop),
+        Data::Union(_) => Ok(()),
+    }
+}
+
+/// Collects every item, or every error if any item failed.
+fn collect<T>(items: impl Iterator<Item = syn::Result<T>>) -> syn::Result<Vec<T>> {
+    let mut errors = Errors::default();
+    let mut collected = Vec::new();
+    items.for_each(|item| match item {
+        Ok(item) => collected.push(item),
+        Err(error) => errors.push(error),
+    });
+    errors.finish(collected)
+}
+
+/// Accumulates errors so that all of them are reported at once.
+#[derive(Default)]
+struct Errors(Option<syn::Error>);
+
+impl Errors {
+    fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    fn extend(&mut self, result: syn::Result<()>) {
+        if let Err(error) = result {
+            self.push(error);
+        }
+    }
+
+    fn finish<T>(self, value: T) -> syn::Result<T> {
+        match self.0 {
+            Some(error) => Err(error),
+            None => Ok(value),
+        }
+    }
+}
+
+/// A single `key` or `key = value` inside `#[enum2egui(...)]`.
+struct Entry {
+    key: Ident,
+    name: String,
+    value: Option<Lit>,
+}
+
+impl Entry {
+    fn key(&self) -> &str {
+        &self.name
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> syn::Error {
+        syn::Error::new(self.key.span(), message)
+    }
+
+    fn flag(&self) -> syn::Result<()> {
+        match &self.value {
+            Some(lit) => Err(syn::Error::new_spanned(
+                lit,
+                format!("`{}` is a flag and takes no value", self.name),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn lit_str(&self) -> syn::Result<&syn::LitStr> {
+        let message = format!("expected a string, as in `{} = \"...\"`", self.name);
+        match &self.value {
+            Some(Lit::Str(lit_str)) => Ok(lit_str),
+            Some(lit) => Err(syn::Error::new_spanned(lit, message)),
+            None => Err(self.error(message)),
+        }
+    }
+
+    fn string(&self) -> syn::Result<String> {
+        self.lit_str().map(syn::LitStr::value)
+    }
+
+    fn path(&self) -> syn::Result<syn::Path> {
+        self.lit_str()?.parse()
+    }
+
+    fn usize(&self) -> syn::Result<usize> {
+        let message = format!("expected an integer, as in `{} = 4`", self.name);
+        match &self.value {
+            Some(Lit::Int(lit_int)) => lit_int.base10_parse(),
+            Some(lit) => Err(syn::Error::new_spanned(lit, message)),
+            None => Err(self.error(message)),
+        }
+    }
+
+    fn picker(&self) -> syn::Result<Picker> {
+        let lit_str = self.lit_str()?;
+        match lit_str.value().as_str() {
+            "combo" => Ok(Picker::Combo),
+            "radio" => Ok(Picker::Radio),
+            "tabs" => Ok(Picker::Tabs),
+            "search" => Ok(Picker::Search),
+            picker => Err(syn::Error::new_spanned(
+                lit_str,
+                format!("unknown picker `{picker}`, expected `combo`, `radio`, `tabs` or `search`"),
+            )),
+        }
+    }
+
+    fn option_style(&self) -> syn::Result<OptionStyle> {
+        let lit_str = self.lit_str()?;
+        match lit_str.value().as_str() {
+            "checkbox" => Ok(OptionStyle::Checkbox),
+            "button" => Ok(OptionStyle::Button),
+            style => Err(syn::Error::new_spanned(
+                lit_str,
+                format!("unknown option_style `{style}`, expected `checkbox` or `button`"),
+            )),
+        }
+    }
+}
+
+/// The first entry with one of `keys`.
+fn find<'a>(entries: &'a [Entry], keys: &[&str]) -> Option<&'a Entry> {
+    entries.iter().find(|entry| keys.contains(&entry.key()))
+}
+
+/// Every entry of the `enum2egui` attributes in `attrs` that is valid at `position`.
+/// Malformed, unknown, misplaced and repeated entries are reported to `errors` instead.
+fn entries(attrs: &[Attribute], position: Position, errors: &mut Errors) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("enum2egui")) {
+        let list = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list,
+            Ok(meta) => {
+                errors.push(syn::Error::new_spanned(
+                    meta,
+                    "expected a list of keys, as in `#[enum2egui(skip)]`",
+                ));
+                continue;
+            }
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+        for nested in list.nested {
+            match entry(nested, position) {
+                Ok(entry) if find(&entries, &[entry.key()]).is_some() => {
+                    errors.push(entry.error(format!("duplicate `{}` attribute", entry.key())));
+                }
+                Ok(entry) => entries.push(entry),
+                Err(error) => errors.push(error),
+            }
+        }
+    }
+    entries
+}
+
+fn entry(nested: NestedMeta, position: Position) -> syn::Result<Entry> {
+    let meta = match nested {
+        NestedMeta::Meta(meta) => meta,
+        NestedMeta::Lit(lit) => {
+            return Err(syn::Error::new_spanned(
+                lit,
+                "expected a key, as in `skip` or `label = \"...\"`",
+            ));
+        }
+    };
+    let (path, value) = match &meta {
+        Meta::Path(path) => (path, None),
+        Meta::NameValue(name_value) => (&name_value.path, Some(name_value.lit.clone())),
+        Meta::List(list) => (&list.path, None),
+    };
+    let Some(key) = path.get_ident().cloned() else {
+        return Err(syn::Error::new_spanned(path, "unknown enum2egui attribute"));
+    };
+
+    let name = key.to_string();
+    if !position.keys().contains(&name.as_str()) {
+        let positions = [
+            Position::Struct,
+            Position::Enum,
+            Position::Variant,
+            Position::Field,
+        ];
+        let message = if positions
+            .iter()
+            .any(|other| other.keys().contains(&name.as_str()))
+        {
+            format!("`{name}` doesn't apply to {}", position.describe())
+        } else {
+            format!("unknown enum2egui attribute `{name}`")
+        };
+        return Err(syn::Error::new(key.span(), message));
+    }
+    if let Meta::List(list) = &meta {
+        return Err(syn::Error::new(
+            list.paren_token.span,
+            format!("`{name}` doesn't take arguments in parentheses"),
+        ));
+    }
+
+    Ok(Entry { key, name, value })
+}
@@ -1,177 +1,304 @@
-use crate::{derive_trait, get_custom_label, has_skip_attr};
+use crate::{
+    Target,
+    attrs::{ParsedField, ParsedVariant, Picker, parse_variants},
+    derive_trait,
+    fields::{field_ui_mut, optional_str},
+    get_doc_comment,
+};
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
-use syn::{DataEnum, Fields, FieldsNamed, FieldsUnnamed, PathArguments, Type};
+use syn::{DataEnum, Fields, FieldsNamed, FieldsUnnamed, Variant};
 
-pub fn derive_enum(name: &Ident, data: &DataEnum) -> TokenStream {
+pub fn derive_enum(target: &Target, data: &DataEnum) -> TokenStream {
     let DataEnum { variants, .. } = data;
+    let name = &target.path();
+    let receiver = &target.receiver();
 
-    let mut selections_mut = proc_macro2::TokenStream::new();
+    let parsed_variants = match parse_variants(variants.iter()) {
+        Ok(parsed_variants) => parsed_variants,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let show_discriminant = target.attrs.show_discriminant;
+    if show_discriminant
+        && let Some(variant) = variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return syn::Error::new_spanned(
+            &variant.ident,
+            "`show_discriminant` requires an enum whose variants have no fields",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let mut choices = Vec::new();
+    let mut selected_arms = proc_macro2::TokenStream::new();
+    let mut select_arms = proc_macro2::TokenStream::new();
     let mut field_match_arms = proc_macro2::TokenStream::new();
 
-    for variant in variants.iter() {
-        if has_skip_attr(&variant.attrs) {
+    for parsed in parsed_variants.iter() {
+        if parsed.attrs.hidden {
             continue;
         }
 
+        let variant = parsed.variant;
         let variant_name = &variant.ident;
+        let index = choices.len();
+        choices.push(variant_choice(name, parsed, show_discriminant));
 
-        // Pass variant attributes to get custom labels
-        let selection_mut = match &variant.fields {
-            Fields::Unit => unit_impl_mut(name, variant_name, &variant.attrs),
-            Fields::Named(fields) => named_impl_mut(name, variant_name, fields, &variant.attrs),
-            Fields::Unnamed(fields) => unnamed_impl_mut(name, variant_name, fields, &variant.attrs),
-        };
-        selections_mut.extend(selection_mut);
+        let pattern = variant_pattern(name, variant);
+        selected_arms.extend(quote! { #pattern => Some(#index), });
+
+        // Skipped variants can't be picked, and only their label is shown while active,
+        // so their payloads needn't implement `GuiInspect`.
+        if parsed.attrs.skip {
+            continue;
+        }
+
+        let default = variant_default(name, variant);
+        select_arms.extend(quote! { #index => *#receiver = #default, });
 
         match &variant.fields {
-            Fields::Named(fields) => {
-                let field = named_match_arm(name, variant_name, fields);
+            Fields::Named(_) => {
+                let field = named_match_arm(name, variant_name, &parsed.fields);
                 field_match_arms.extend(field);
             }
-            Fields::Unnamed(fields) => {
-                let field = unnamed_match_arm(name, variant_name, fields);
+            Fields::Unnamed(_) => {
+                let field = unnamed_match_arm(name, variant_name, &parsed.fields);
                 field_match_arms.extend(field);
             }
             Fields::Unit => {}
         };
     }
 
-    let gui: proc_macro2::TokenStream = quote! {
-        ui.label(format!("{self}"));
-    }
-    .to_token_stream();
+    let selected_text = selected_text(target, &parsed_variants);
+    let picker = target.attrs.picker.unwrap_or(Picker::Combo);
+
+    let (gui, picked) = if show_discriminant {
+        let discriminant = discriminant(name, receiver, variants.iter());
+        let gui = quote! {
+            ui.label(format!("{} (= {})", #selected_text, #discriminant));
+        };
+        let picked = quote! {
+            ui.horizontal(|ui| {
+                let picked =
+                    enum2egui::variant_picker_ui(ui, picker, #selected_text, &choices, selected);
+                enum2egui::discriminant_ui_mut(ui, &choices, #discriminant).or(picked)
+            })
+            .inner
+        };
+        (gui, picked)
+    } else {
+        let gui = quote! {
+            ui.label(#selected_text);
+        };
+        let picked = quote! {
+            enum2egui::variant_picker_ui(ui, picker, #selected_text, &choices, selected)
+        };
+        (gui, picked)
+    };
 
     let gui_mut: proc_macro2::TokenStream = quote! {
-        ui.vertical(|ui| {
-            egui::ComboBox::from_id_salt(ui.next_auto_id())
-                .selected_text(format!("{self}"))
-                .show_ui(ui, |ui| {
-                    #selections_mut
-                });
-
-            match self {
-                #field_match_arms
-                _ => {}
-            }
+        let picker = enum2egui::current_picker(ui, #picker);
+        enum2egui::nested_ui(ui, |ui| {
+            ui.vertical(|ui| {
+                let selected = match #receiver {
+                    #selected_arms
+                    _ => None,
+                };
+                let choices = [#(#choices),*];
+                if let Some(index) = #picked {
+                    match index {
+                        #select_arms
+                        _ => {}
+                    }
+                }
+
+                match #receiver {
+                    #field_match_arms
+                    _ => {}
+                }
+            });
         });
     }
     .to_token_stream();
 
-    derive_trait(name, gui, gui_mut)
+    let mut derived: TokenStream2 = derive_trait(target, gui, gui_mut).into();
+    derived.extend(unit_variants_impl(target, &parsed_variants));
+    derived.into()
 }
 
-fn unit_impl_mut(
-    name: &Ident,
-    variant_name: &Ident,
-    attrs: &Vec<syn::Attribute>,
-) -> proc_macro2::TokenStream {
-    let label = get_custom_label(attrs).unwrap_or_else(|| variant_name.to_string());
+/// Implements `UnitVariants` for C-like enums, so that `#[enum2egui(flags)]` fields
+/// can hold them. Remote enums are skipped, as the orphan rule forbids the impl.
+/// Variants that can't be picked aren't offered as flags either.
+fn unit_variants_impl(target: &Target, variants: &[ParsedVariant]) -> TokenStream2 {
+    if target.attrs.remote.is_some()
+        || !variants
+            .iter()
+            .all(|parsed| matches!(parsed.variant.fields, Fields::Unit))
+    {
+        return TokenStream2::new();
+    }
+
+    let name = target.name;
+    let entries = variants
+        .iter()
+        .filter(|parsed| !parsed.attrs.skip && !parsed.attrs.hidden)
+        .map(|parsed| {
+            let variant_name = &parsed.variant.ident;
+            let label = variant_label(parsed);
+            quote! { (#name::#variant_name, #label), }
+        });
 
     quote! {
-        if ui
-            .selectable_label(matches!(self, #name::#variant_name), #label)
-            .clicked()
-        {
-            *self = #name::#variant_name;
+        impl enum2egui::UnitVariants for #name {
+            fn unit_variants() -> Vec<(Self, &'static str)> {
+                vec![#(#entries)*]
+            }
         }
     }
-    .to_token_stream()
 }
 
-fn named_impl_mut(
-    name: &Ident,
-    variant_name: &Ident,
-    fields: &FieldsNamed,
-    attrs: &Vec<syn::Attribute>,
-) -> proc_macro2::TokenStream {
-    let mut default_fields = proc_macro2::TokenStream::new();
-    let label = get_custom_label(attrs).unwrap_or_else(|| variant_name.to_string());
+/// The text shown for the active variant. Derived enums display themselves,
+/// but a remote enum may not implement `Display`, so its variant labels are used instead.
+fn selected_text(target: &Target, variants: &[ParsedVariant]) -> TokenStream2 {
+    if target.attrs.remote.is_none() {
+        return quote! { format!("{self}") };
+    }
 
-    let FieldsNamed { named, .. } = fields;
-    named.iter().for_each(|field| {
-        let field_name = &field.ident;
-        let default_field = quote! {
-            #field_name: Default::default(),
-        }
-        .to_token_stream();
-        default_fields.extend(default_field);
+    let name = target.path();
+    let receiver = target.receiver();
+    let arms = variants.iter().map(|parsed| {
+        let label = variant_label(parsed);
+        let pattern = variant_pattern(&name, parsed.variant);
+        quote! { #pattern => #label, }
     });
 
     quote! {
-        if ui
-            .selectable_label(matches!(self, #name::#variant_name { .. }), #label)
-            .clicked()
-        {
-            *self = #name::#variant_name { #default_fields };
+        match #receiver {
+            #(#arms)*
         }
     }
-    .to_token_stream()
 }
 
-fn unnamed_impl_mut(
-    name: &Ident,
-    variant_name: &Ident,
-    fields: &FieldsUnnamed,
-    attrs: &Vec<syn::Attribute>,
-) -> proc_macro2::TokenStream {
-    let mut default_fields = proc_macro2::TokenStream::new();
-    let label = get_custom_label(attrs).unwrap_or_else(|| variant_name.to_string());
-
-    let FieldsUnnamed { unnamed, .. } = fields;
-    unnamed.iter().for_each(|field| {
-        let field_type = &field.ty;
-        let default_field: proc_macro2::TokenStream = if is_vec_type(field_type) {
-            quote! {
-                Vec::default(),
-            }
-        } else {
-            quote! {
-                #field_type::default(),
-            }
-        }
-        .to_token_stream();
-        default_fields.extend(default_field);
+/// The discriminant of the active variant of a C-like enum, as an `i128`
+/// so that any `repr` fits.
+fn discriminant<'a>(
+    name: &TokenStream2,
+    receiver: &TokenStream2,
+    variants: impl Iterator<Item = &'a Variant>,
+) -> TokenStream2 {
+    let arms = variants.map(|variant| {
+        let variant_name = &variant.ident;
+        quote! { #name::#variant_name => #name::#variant_name as i128, }
     });
+    quote! {
+        match #receiver {
+            #(#arms)*
+        }
+    }
+}
+
+/// The variant's name, or its `#[enum2egui(label = "...")]`.
+fn variant_label(parsed: &ParsedVariant) -> String {
+    parsed
+        .attrs
+        .label
+        .clone()
+        .unwrap_or_else(|| parsed.variant.ident.to_string())
+}
 
+/// The `VariantChoice` describing `parsed` in the picker.
+fn variant_choice(
+    name: &TokenStream2,
+    parsed: &ParsedVariant,
+    show_discriminant: bool,
+) -> TokenStream2 {
+    let variant_name = &parsed.variant.ident;
+    let discriminant = if show_discriminant {
+        quote! { Some(#name::#variant_name as i128) }
+    } else {
+        quote! { None }
+    };
+    let label = variant_label(parsed);
+    let selectable = !parsed.attrs.skip;
+    let group = optional_str(parsed.attrs.group.as_deref());
+    let doc = get_doc_comment(&parsed.variant.attrs);
+    let tooltip = optional_str(parsed.attrs.tooltip.as_deref().or(doc.as_deref()));
     quote! {
-        if ui
-            .selectable_label(matches!(self, #name::#variant_name( .. )), #label)
-            .clicked()
-        {
-            *self = #name::#variant_name(#default_fields);
+        enum2egui::VariantChoice {
+            label: #label,
+            selectable: #selectable,
+            group: #group,
+            tooltip: #tooltip,
+            discriminant: #discriminant,
+        }
+    }
+}
+
+/// A pattern matching any value of `variant`.
+fn variant_pattern(name: &TokenStream2, variant: &Variant) -> TokenStream2 {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        Fields::Unit => quote! { #name::#variant_name },
+        Fields::Named(_) => quote! { #name::#variant_name { .. } },
+        Fields::Unnamed(_) => quote! { #name::#variant_name(..) },
+    }
+}
+
+/// `variant` with every field defaulted, assigned when the variant is picked.
+fn variant_default(name: &TokenStream2, variant: &Variant) -> TokenStream2 {
+    let variant_name = &variant.ident;
+    match &variant.fields {
+        Fields::Unit => quote! { #name::#variant_name },
+        Fields::Named(FieldsNamed { named, .. }) => {
+            let field_names = named.iter().map(|field| &field.ident);
+            quote! { #name::#variant_name { #(#field_names: Default::default(),)* } }
+        }
+        Fields::Unnamed(FieldsUnnamed { unnamed, .. }) => {
+            // Qualified so that generic payloads like `Vec<T>` or `Box<Self>` parse as paths.
+            let field_types = unnamed.iter().map(|field| &field.ty);
+            quote! { #name::#variant_name(#(<#field_types as Default>::default(),)*) }
         }
     }
-    .to_token_stream()
 }
 
 fn named_match_arm(
-    name: &Ident,
+    name: &TokenStream2,
     variant_name: &Ident,
-    fields: &FieldsNamed,
+    fields: &[ParsedField],
 ) -> proc_macro2::TokenStream {
-    let FieldsNamed { named, .. } = fields;
-
     let mut match_fields = proc_macro2::TokenStream::new();
     let mut labels = proc_macro2::TokenStream::new();
 
-    for field in named.iter() {
-        let field_name = &field.ident;
+    for field in fields.iter() {
+        let field_name = &field.field.ident;
+        if field.attrs.skip {
+            match_fields.extend(quote! { #field_name: _, });
+            continue;
+        }
+
         let field_entry: proc_macro2::TokenStream = quote! {
             #field_name,
         }
         .to_token_stream();
         match_fields.extend(field_entry);
 
-        let field_type = &field.ty;
-        let label = get_custom_label(&field.attrs)
+        let label = field
+            .attrs
+            .label
+            .clone()
             .unwrap_or_else(|| field_name.as_ref().unwrap().to_string());
 
+        let widget = field_ui_mut(field, field_name.to_token_stream());
+
         let label_block: proc_macro2::TokenStream = quote! {
             ui.horizontal(|ui| {
                 ui.label(#label);
-                <#field_type as GuiInspect>::ui_mut(#field_name, ui);
+                #widget
             });
         }
         .to_token_stream();
@@ -189,16 +316,19 @@ fn named_match_arm(
 }
 
 fn unnamed_match_arm(
-    name: &Ident,
+    name: &TokenStream2,
     variant_name: &Ident,
-    fields: &FieldsUnnamed,
+    fields: &[ParsedField],
 ) -> proc_macro2::TokenStream {
-    let FieldsUnnamed { unnamed, .. } = fields;
-
     let mut match_fields = proc_macro2::TokenStream::new();
     let mut labels = proc_macro2::TokenStream::new();
 
-    for (index, field) in unnamed.iter().enumerate() {
+    for (index, field) in fields.iter().enumerate() {
+        if field.attrs.skip {
+            match_fields.extend(quote! { _, });
+            continue;
+        }
+
         let field_name = Ident::new(&format!("field_{index}"), Span::call_site());
         let field_entry: proc_macro2::TokenStream = quote! {
             #field_name,
@@ -206,13 +336,18 @@ fn unnamed_match_arm(
         .to_token_stream();
         match_fields.extend(field_entry);
 
-        let field_type = &field.ty;
-        let label = get_custom_label(&field.attrs).unwrap_or_else(|| format!("field_{}", index));
+        let label = field
+            .attrs
+            .label
+            .clone()
+            .unwrap_or_else(|| format!("field_{}", index));
+
+        let widget = field_ui_mut(field, field_name.to_token_stream());
 
         let label_block: proc_macro2::TokenStream = quote! {
             ui.horizontal(|ui| {
                 ui.label(#label);
-                <#field_type as GuiInspect>::ui_mut(#field_name, ui);
+                #widget
             });
         }
         .to_token_stream();
@@ -226,13 +361,3 @@ fn unnamed_match_arm(
     }
     .to_token_stream()
 }
-
-fn is_vec_type(ty: &Type) -> bool {
-    if let Type::Path(type_path) = ty
-        && let Some(segment) = type_path.path.segments.last()
-    {
-        return segment.ident == "Vec"
-            && matches!(segment.arguments, PathArguments::AngleBracketed(_));
-    }
-    false
-}
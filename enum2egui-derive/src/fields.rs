@@ -0,0 +1,206 @@
+use crate::attrs::{FieldAttrs, ParsedField, is_path_to};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the read-only widget for `field`, where `value` evaluates to `&T`.
+pub(crate) fn field_ui(field: &ParsedField, value: TokenStream) -> TokenStream {
+    let field_ty = &field.field.ty;
+    let attrs = &field.attrs;
+
+    if let Some(with) = &attrs.with {
+        return quote! {
+            #with::ui(#value, ui);
+        };
+    }
+
+    if attrs.flags {
+        return quote! {
+            enum2egui::flags_ui(#value, ui);
+        };
+    }
+
+    if attrs.matrix {
+        return quote! {
+            enum2egui::matrix_ui(#value, ui);
+        };
+    }
+
+    if attrs.parse {
+        return quote! {
+            enum2egui::display_ui(#value, ui);
+        };
+    }
+
+    if attrs.color {
+        let options = color_options(attrs);
+        return quote! {
+            enum2egui::color_ui(#value, ui, #options);
+        };
+    }
+
+    if attrs.has_vec_options() {
+        return quote! {
+            enum2egui::vec_ui(#value, ui);
+        };
+    }
+
+    if attrs.has_text_options() {
+        let options = text_options(attrs);
+        return quote! {
+            enum2egui::text_ui(#value, ui, #options);
+        };
+    }
+
+    quote! {
+        <#field_ty as GuiInspect>::ui(#value, ui);
+    }
+}
+
+/// Generates the editable widget for `field`, where `value` evaluates to `&mut T`.
+pub(crate) fn field_ui_mut(field: &ParsedField, value: TokenStream) -> TokenStream {
+    let widget = widget_ui_mut(field, value);
+    match field.attrs.picker {
+        Some(picker) => {
+            quote! {
+                enum2egui::with_picker(ui, #picker, |ui| {
+                    #widget
+                });
+            }
+        }
+        None => widget,
+    }
+}
+
+fn widget_ui_mut(field: &ParsedField, value: TokenStream) -> TokenStream {
+    let field_ty = &field.field.ty;
+    let attrs = &field.attrs;
+
+    if let Some(with) = &attrs.with {
+        return quote! {
+            #with::ui_mut(#value, ui);
+        };
+    }
+
+    if attrs.flags {
+        return quote! {
+            enum2egui::flags_ui_mut(#value, ui);
+        };
+    }
+
+    if attrs.matrix {
+        return quote! {
+            enum2egui::matrix_ui_mut(#value, ui);
+        };
+    }
+
+    if attrs.parse {
+        return quote! {
+            enum2egui::parse_ui_mut(#value, ui);
+        };
+    }
+
+    if attrs.color {
+        let options = color_options(attrs);
+        return quote! {
+            enum2egui::color_ui_mut(#value, ui, #options);
+        };
+    }
+
+    if let Some(style) = attrs.option_style {
+        return quote! {
+            enum2egui::option_ui_mut(#value, ui, #style);
+        };
+    }
+
+    if attrs.has_vec_options() {
+        let bounds = length_bounds(attrs);
+        let new_item = new_item(attrs);
+        return quote! {
+            enum2egui::vec_ui_mut(#value, ui, #bounds, #new_item);
+        };
+    }
+
+    if attrs.has_text_options() {
+        let options = text_options(attrs);
+        return quote! {
+            enum2egui::text_ui_mut(#value, ui, #options);
+        };
+    }
+
+    // The pending-element editor needs more of the element type than `GuiInspect` for `HashSet` does.
+    if is_path_to(field_ty, "HashSet") {
+        return quote! {
+            enum2egui::hash_set_ui_mut(#value, ui);
+        };
+    }
+
+    quote! {
+        <#field_ty as GuiInspect>::ui_mut(#value, ui);
+    }
+}
+
+fn color_options(attrs: &FieldAttrs) -> TokenStream {
+    let FieldAttrs { alpha, hsva, .. } = attrs;
+    quote! {
+        enum2egui::ColorOptions {
+            alpha: #alpha,
+            hsva: #hsva,
+        }
+    }
+}
+
+fn length_bounds(attrs: &FieldAttrs) -> TokenStream {
+    let min_len = optional_usize(attrs.min_len);
+    let max_len = optional_usize(attrs.max_len);
+    quote! {
+        enum2egui::LengthBounds {
+            min_len: #min_len,
+            max_len: #max_len,
+        }
+    }
+}
+
+fn new_item(attrs: &FieldAttrs) -> TokenStream {
+    match &attrs.new_item {
+        Some(path) => quote! { #path },
+        None => quote! { Default::default },
+    }
+}
+
+fn text_options(attrs: &FieldAttrs) -> TokenStream {
+    let FieldAttrs {
+        multiline,
+        password,
+        code,
+        ..
+    } = attrs;
+    let rows = optional_usize(attrs.rows);
+    let max_chars = optional_usize(attrs.max_chars);
+    let hint = optional_str(attrs.hint.as_deref());
+    let language = optional_str(attrs.language.as_deref());
+    quote! {
+        enum2egui::TextOptions {
+            multiline: #multiline,
+            rows: #rows,
+            password: #password,
+            hint: #hint,
+            max_chars: #max_chars,
+            code: #code,
+            language: #language,
+        }
+    }
+}
+
+fn optional_usize(value: Option<usize>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+pub(crate) fn optional_str(value: Option<&str>) -> TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
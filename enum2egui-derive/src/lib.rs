@@ -1,12 +1,15 @@
+mod attrs;
 mod enums;
+mod fields;
 mod structs;
 
+use attrs::{ContainerAttrs, Position};
 use enums::derive_enum;
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{ToTokens, quote};
 use structs::derive_struct;
-use syn::{Attribute, Data, DeriveInput, Error, Lit, Meta, NestedMeta, parse_macro_input};
+use syn::{Attribute, Data, DeriveInput, Error, Lit, Meta, Visibility, parse_macro_input};
 
 macro_rules! derive_error {
     ($string: tt) => {
@@ -19,71 +22,117 @@ macro_rules! derive_error {
 #[proc_macro_derive(Gui, attributes(enum2egui))]
 pub fn derive_gui(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let name = &input.ident;
 
-    match &input.data {
-        Data::Struct(data) => derive_struct(name, data),
-        Data::Enum(data) => derive_enum(name, data),
-        Data::Union(..) => derive_error!("enum2egui does not support unions"),
-    }
-}
-
-pub(crate) fn derive_trait(
-    name: &Ident,
-    gui: proc_macro2::TokenStream,
-    gui_mut: proc_macro2::TokenStream,
-) -> TokenStream {
-    quote! {
-        impl enum2egui::GuiInspect for #name {
-            fn ui(&self, ui: &mut egui::Ui) {
-                #gui
-            }
-
-            fn ui_mut(&mut self, ui: &mut egui::Ui) {
-                #gui_mut
+    let position = match &input.data {
+        Data::Struct(_) => Position::Struct,
+        Data::Enum(_) => Position::Enum,
+        Data::Union(..) => return derive_error!("enum2egui does not support unions"),
+    };
+    let attrs = match ContainerAttrs::parse(&input.attrs, position) {
+        Ok(attrs) => attrs,
+        Err(mut error) => {
+            if let Err(data_error) = attrs::check_data(&input.data) {
+                error.combine(data_error);
             }
+            return error.to_compile_error().into();
         }
+    };
+
+    let target = Target {
+        name: &input.ident,
+        vis: &input.vis,
+        attrs,
+    };
+
+    match &input.data {
+        Data::Struct(data) => derive_struct(&target, data),
+        Data::Enum(data) => derive_enum(&target, data),
+        Data::Union(..) => unreachable!("unions are rejected above"),
     }
-    .to_token_stream()
-    .into()
 }
 
-pub(crate) fn has_skip_attr(attrs: &[Attribute]) -> bool {
-    attrs.iter().any(is_enum2egui_skip_attr)
+/// The type that generated code inspects.
+///
+/// With `#[enum2egui(remote = "other::Type")]` the deriving type is only a mirror
+/// of a foreign type, and the generated code works on the foreign type instead.
+pub(crate) struct Target<'a> {
+    pub name: &'a Ident,
+    pub vis: &'a Visibility,
+    pub attrs: ContainerAttrs,
 }
 
-fn is_enum2egui_skip_attr(attr: &Attribute) -> bool {
-    if let Ok(Meta::List(meta_list)) = attr.parse_meta()
-        && meta_list.path.is_ident("enum2egui")
-    {
-        return has_skip_path(&meta_list.nested);
+impl Target<'_> {
+    /// The path used to match and construct the inspected type.
+    pub fn path(&self) -> TokenStream2 {
+        match &self.attrs.remote {
+            Some(remote) => remote.to_token_stream(),
+            None => self.name.to_token_stream(),
+        }
     }
-    false
-}
 
-fn has_skip_path(nested: &syn::punctuated::Punctuated<NestedMeta, syn::token::Comma>) -> bool {
-    nested.iter().any(|nested| {
-        if let NestedMeta::Meta(Meta::Path(path)) = nested {
-            return path.is_ident("skip");
+    /// The inspected value: `self`, or the `value` argument of a remote derive.
+    pub fn receiver(&self) -> TokenStream2 {
+        match &self.attrs.remote {
+            Some(_) => quote! { value },
+            None => quote! { self },
         }
-        false
-    })
+    }
 }
 
-pub(crate) fn get_custom_label(attrs: &Vec<syn::Attribute>) -> Option<String> {
-    for attr in attrs {
-        if attr.path.is_ident("enum2egui")
-            && let Ok(syn::Meta::List(meta_list)) = attr.parse_meta()
-        {
-            for nested_meta in meta_list.nested {
-                if let NestedMeta::Meta(Meta::NameValue(nv)) = nested_meta
-                    && nv.path.is_ident("label")
-                    && let Lit::Str(lit_str) = nv.lit
-                {
-                    return Some(lit_str.value());
+/// Implements `GuiInspect` for the target, or for a remote derive,
+/// generates `ui` and `ui_mut` functions on the mirror type that take the foreign type.
+/// These are what `#[enum2egui(with = "Mirror")]` fields call.
+pub(crate) fn derive_trait(
+    target: &Target,
+    gui: TokenStream2,
+    gui_mut: TokenStream2,
+) -> TokenStream {
+    let name = target.name;
+
+    match &target.attrs.remote {
+        Some(remote) => {
+            let vis = target.vis;
+            quote! {
+                impl #name {
+                    #vis fn ui(value: &#remote, ui: &mut egui::Ui) {
+                        #gui
+                    }
+
+                    #vis fn ui_mut(value: &mut #remote, ui: &mut egui::Ui) {
+                        #gui_mut
+                    }
                 }
             }
         }
+        None => quote! {
+            impl enum2egui::GuiInspect for #name {
+                fn ui(&self, ui: &mut egui::Ui) {
+                    #gui
+                }
+
+                fn ui_mut(&mut self, ui: &mut egui::Ui) {
+                    #gui_mut
+                }
+            }
+        },
     }
-    None
+    .to_token_stream()
+    .into()
+}
+
+/// The doc comment of an item, with each line trimmed.
+pub(crate) fn get_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines = attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(syn::MetaNameValue {
+                lit: Lit::Str(lit_str),
+                ..
+            })) => Some(lit_str.value().trim().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let doc = lines.join("\n").trim().to_string();
+    (!doc.is_empty()).then_some(doc)
 }
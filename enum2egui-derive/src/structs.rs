@@ -1,45 +1,66 @@
-use crate::{derive_trait, get_custom_label, has_skip_attr};
+use crate::{
+    Target,
+    attrs::{ParsedField, parse_fields},
+    derive_trait,
+    fields::{field_ui, field_ui_mut},
+};
 use proc_macro::TokenStream;
-use proc_macro2::{Ident, TokenStream as TokenStream2};
+use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, quote, quote_spanned};
-use syn::{DataStruct, Fields, FieldsNamed, FieldsUnnamed, spanned::Spanned};
+use syn::{DataStruct, Fields, spanned::Spanned};
 
-pub fn derive_struct(name: &Ident, data: &DataStruct) -> TokenStream {
+pub fn derive_struct(target: &Target, data: &DataStruct) -> TokenStream {
     let DataStruct { fields, .. } = data;
 
+    let parsed_fields = match parse_fields(fields) {
+        Ok(parsed_fields) => parsed_fields,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
     match fields {
-        Fields::Named(named_fields) => named_field_struct_impl(name, named_fields),
-        Fields::Unnamed(unnamed_fields) => tuple_struct_impl(name, unnamed_fields),
-        Fields::Unit => generate_unit_struct_impl(name),
+        Fields::Named(_) => named_field_struct_impl(target, &parsed_fields),
+        Fields::Unnamed(_) => tuple_struct_impl(target, &parsed_fields),
+        Fields::Unit => generate_unit_struct_impl(target),
     }
 }
 
-fn generate_unit_struct_impl(name: &Ident) -> TokenStream {
-    derive_trait(
-        name,
-        proc_macro2::TokenStream::new(),
-        proc_macro2::TokenStream::new(),
-    )
+/// The struct's name, or its `#[enum2egui(label = "...")]`.
+fn struct_label(target: &Target) -> String {
+    target
+        .attrs
+        .label
+        .clone()
+        .unwrap_or_else(|| target.name.to_string())
+}
+
+/// Unit structs have nothing to edit, so both views show the struct's label.
+fn generate_unit_struct_impl(target: &Target) -> TokenStream {
+    let label = struct_label(target);
+    let gui = quote! {
+        ui.label(#label);
+    };
+    derive_trait(target, gui.clone(), gui)
 }
 
-fn tuple_struct_impl(name: &Ident, fields: &FieldsUnnamed) -> TokenStream {
-    let FieldsUnnamed { unnamed, .. } = fields;
-    let (field_blocks, field_blocks_mut) = tuple_struct_field_blocks(unnamed);
+fn tuple_struct_impl(target: &Target, fields: &[ParsedField]) -> TokenStream {
+    let (field_blocks, field_blocks_mut) = tuple_struct_field_blocks(fields, &target.receiver());
 
-    let gui = struct_ui(name, field_blocks);
-    let gui_mut = struct_ui(name, field_blocks_mut);
-    derive_trait(name, gui, gui_mut)
+    let label = struct_label(target);
+    let gui = struct_ui(&label, field_blocks);
+    let gui_mut = struct_ui(&label, field_blocks_mut);
+    derive_trait(target, gui, gui_mut)
 }
 
 fn tuple_struct_field_blocks(
-    named: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    fields: &[ParsedField],
+    receiver: &TokenStream2,
 ) -> (TokenStream2, TokenStream2) {
     let mut field_blocks = TokenStream2::new();
     let mut field_blocks_mut = TokenStream2::new();
 
-    named.into_iter().enumerate().for_each(|(index, field)| {
-        field_blocks.extend(unnamed_field_block(field, index));
-        field_blocks_mut.extend(unnamed_field_block_mut(field, index));
+    fields.iter().enumerate().for_each(|(index, field)| {
+        field_blocks.extend(unnamed_field_block(field, index, receiver));
+        field_blocks_mut.extend(unnamed_field_block_mut(field, index, receiver));
     });
 
     (field_blocks, field_blocks_mut)
@@ -49,116 +70,137 @@ fn unnamed_field_label(index: usize) -> String {
     format!("field_{}", index)
 }
 
-fn unnamed_field_block(field: &syn::Field, index: usize) -> proc_macro2::TokenStream {
-    if has_skip_attr(&field.attrs) {
+fn unnamed_field_block(
+    field: &ParsedField,
+    index: usize,
+    receiver: &TokenStream2,
+) -> proc_macro2::TokenStream {
+    if field.attrs.skip {
         return quote! {};
     }
 
     let field_name = unnamed_field_label(index);
-    let field_type = &field.ty;
     let index = syn::Index::from(index);
 
-    let label = get_custom_label(&field.attrs).unwrap_or(field_name);
+    let label = field.attrs.label.clone().unwrap_or(field_name);
+
+    let widget = field_ui(field, quote! { &#receiver.#index });
 
-    quote_spanned! { field.span() =>
+    quote_spanned! { field.field.span() =>
         ui.horizontal(|ui| {
             ui.label(#label);
-            <#field_type as GuiInspect>::ui(&self.#index, ui);
+            #widget
         });
     }
     .to_token_stream()
 }
 
-fn unnamed_field_block_mut(field: &syn::Field, index: usize) -> proc_macro2::TokenStream {
-    if has_skip_attr(&field.attrs) {
+fn unnamed_field_block_mut(
+    field: &ParsedField,
+    index: usize,
+    receiver: &TokenStream2,
+) -> proc_macro2::TokenStream {
+    if field.attrs.skip {
         return quote! {};
     }
 
     let field_name = unnamed_field_label(index);
-    let field_type = &field.ty;
     let index = syn::Index::from(index);
 
-    let label = get_custom_label(&field.attrs).unwrap_or(field_name);
+    let label = field.attrs.label.clone().unwrap_or(field_name);
 
-    quote_spanned! { field.span() =>
+    let widget = field_ui_mut(field, quote! { &mut #receiver.#index });
+
+    quote_spanned! { field.field.span() =>
         ui.horizontal(|ui| {
             ui.label(#label);
-            <#field_type as GuiInspect>::ui_mut(&mut self.#index, ui);
+            #widget
         });
     }
     .to_token_stream()
 }
 
-fn named_field_struct_impl(name: &Ident, fields: &FieldsNamed) -> TokenStream {
-    let FieldsNamed { named, .. } = fields;
-    let (field_blocks, field_blocks_mut) = named_struct_field_blocks(named);
+fn named_field_struct_impl(target: &Target, fields: &[ParsedField]) -> TokenStream {
+    let (field_blocks, field_blocks_mut) = named_struct_field_blocks(fields, &target.receiver());
 
-    let gui = struct_ui(name, field_blocks);
-    let gui_mut = struct_ui(name, field_blocks_mut);
-    derive_trait(name, gui, gui_mut)
+    let label = struct_label(target);
+    let gui = struct_ui(&label, field_blocks);
+    let gui_mut = struct_ui(&label, field_blocks_mut);
+    derive_trait(target, gui, gui_mut)
 }
 
 fn named_struct_field_blocks(
-    named: &syn::punctuated::Punctuated<syn::Field, syn::token::Comma>,
+    fields: &[ParsedField],
+    receiver: &TokenStream2,
 ) -> (TokenStream2, TokenStream2) {
     let mut field_blocks = TokenStream2::new();
     let mut field_blocks_mut = TokenStream2::new();
 
-    named.into_iter().for_each(|field| {
-        field_blocks.extend(named_field_block(field));
-        field_blocks_mut.extend(named_field_block_mut(field));
+    fields.iter().for_each(|field| {
+        field_blocks.extend(named_field_block(field, receiver));
+        field_blocks_mut.extend(named_field_block_mut(field, receiver));
     });
 
     (field_blocks, field_blocks_mut)
 }
 
-fn named_field_block(field: &syn::Field) -> proc_macro2::TokenStream {
-    if has_skip_attr(&field.attrs) {
+fn named_field_block(field: &ParsedField, receiver: &TokenStream2) -> proc_macro2::TokenStream {
+    if field.attrs.skip {
         return quote! {};
     }
 
-    let field_name = &field.ident;
-    let field_ty = &field.ty;
+    let field_name = &field.field.ident;
+
+    let label = field
+        .attrs
+        .label
+        .clone()
+        .unwrap_or_else(|| field_name.as_ref().unwrap().to_string());
 
-    let label =
-        get_custom_label(&field.attrs).unwrap_or_else(|| field_name.as_ref().unwrap().to_string());
+    let widget = field_ui(field, quote! { &#receiver.#field_name });
 
-    quote_spanned! { field.span() =>
+    quote_spanned! { field.field.span() =>
         ui.horizontal(|ui| {
             ui.label(#label);
-            <#field_ty as GuiInspect>::ui(&self.#field_name, ui);
+            #widget
         });
     }
     .to_token_stream()
 }
 
-fn named_field_block_mut(field: &syn::Field) -> proc_macro2::TokenStream {
-    if has_skip_attr(&field.attrs) {
+fn named_field_block_mut(field: &ParsedField, receiver: &TokenStream2) -> proc_macro2::TokenStream {
+    if field.attrs.skip {
         return quote! {};
     }
 
-    let field_name = &field.ident;
-    let field_ty = &field.ty;
+    let field_name = &field.field.ident;
+
+    let label = field
+        .attrs
+        .label
+        .clone()
+        .unwrap_or_else(|| field_name.as_ref().unwrap().to_string());
 
-    let label =
-        get_custom_label(&field.attrs).unwrap_or_else(|| field_name.as_ref().unwrap().to_string());
+    let widget = field_ui_mut(field, quote! { &mut #receiver.#field_name });
 
-    quote_spanned! { field.span() =>
+    quote_spanned! { field.field.span() =>
         ui.horizontal(|ui| {
             ui.label(#label);
-            <#field_ty as GuiInspect>::ui_mut(&mut self.#field_name, ui);
+            #widget
         });
     }
     .to_token_stream()
 }
 
-fn struct_ui(name: &Ident, fields: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+fn struct_ui(label: &str, fields: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
     quote! {
-        ui.group(|ui| {
-            ui.horizontal(|ui| {
-                ui.label(stringify!(#name));
-                ui.vertical(|ui| {
-                    #fields
+        enum2egui::nested_ui(ui, |ui| {
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(#label);
+                    ui.vertical(|ui| {
+                        #fields
+                    });
                 });
             });
         });
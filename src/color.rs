@@ -0,0 +1,264 @@
+use crate::GuiInspect;
+use egui::{
+    Color32, Rgba, Ui,
+    ecolor::{Hsva, linear_f32_from_gamma_u8, linear_f32_from_linear_u8},
+    widgets::color_picker::{Alpha, color_edit_button_hsva, color_edit_button_rgba, show_color},
+};
+
+/// How a field marked `#[enum2egui(color)]` stores its color.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColorOptions {
+    /// Whether the picker edits alpha, set with `#[enum2egui(color, alpha)]`.
+    /// Without it, any stored alpha is left untouched.
+    pub alpha: bool,
+    /// Whether float components are hue, saturation and value
+    /// instead of linear RGB, set with `#[enum2egui(color, hsva)]`.
+    pub hsva: bool,
+}
+
+impl ColorOptions {
+    fn alpha_mode(&self) -> Alpha {
+        if self.alpha {
+            Alpha::OnlyBlend
+        } else {
+            Alpha::Opaque
+        }
+    }
+}
+
+/// Plain data that can be edited with egui's color picker.
+///
+/// Implemented for `[u8; 3]`, `[u8; 4]`, `[f32; 3]`, `[f32; 4]`,
+/// `(u8, u8, u8)`, `(u8, u8, u8, u8)` and packed `u32` colors.
+/// Byte components are sRGB, float components are linear RGB (or HSV with `hsva`),
+/// and a packed `u32` is `0xRRGGBB`, or `0xRRGGBBAA` with `alpha`.
+pub trait ColorComponents {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva;
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions);
+}
+
+impl ColorComponents for [u8; 3] {
+    fn to_hsva(&self, _options: ColorOptions) -> Hsva {
+        Hsva::from_srgb(*self)
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, _options: ColorOptions) {
+        *self = hsva.to_srgb();
+    }
+}
+
+/// `Hsva::from_srgba_unmultiplied` goes through premultiplied `Color32`,
+/// which loses precision at low alpha, so the components are converted directly.
+fn hsva_from_srgba([r, g, b, a]: [u8; 4]) -> Hsva {
+    Hsva::from_rgba_unmultiplied(
+        linear_f32_from_gamma_u8(r),
+        linear_f32_from_gamma_u8(g),
+        linear_f32_from_gamma_u8(b),
+        linear_f32_from_linear_u8(a),
+    )
+}
+
+impl ColorComponents for [u8; 4] {
+    fn to_hsva(&self, _options: ColorOptions) -> Hsva {
+        hsva_from_srgba(*self)
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions) {
+        let [r, g, b, a] = hsva.to_srgba_unmultiplied();
+        *self = [r, g, b, if options.alpha { a } else { self[3] }];
+    }
+}
+
+impl ColorComponents for [f32; 3] {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva {
+        let [x, y, z] = *self;
+        if options.hsva {
+            Hsva::new(x, y, z, 1.0)
+        } else {
+            Hsva::from_rgb(*self)
+        }
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions) {
+        *self = if options.hsva {
+            [hsva.h, hsva.s, hsva.v]
+        } else {
+            hsva.to_rgb()
+        };
+    }
+}
+
+impl ColorComponents for [f32; 4] {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva {
+        let [x, y, z, a] = *self;
+        if options.hsva {
+            Hsva::new(x, y, z, a)
+        } else {
+            Hsva::from_rgba_unmultiplied(x, y, z, a)
+        }
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions) {
+        let [x, y, z, a] = if options.hsva {
+            [hsva.h, hsva.s, hsva.v, hsva.a]
+        } else {
+            hsva.to_rgba_unmultiplied()
+        };
+        *self = [x, y, z, if options.alpha { a } else { self[3] }];
+    }
+}
+
+impl ColorComponents for (u8, u8, u8) {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva {
+        [self.0, self.1, self.2].to_hsva(options)
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, _options: ColorOptions) {
+        let [r, g, b] = hsva.to_srgb();
+        *self = (r, g, b);
+    }
+}
+
+impl ColorComponents for (u8, u8, u8, u8) {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva {
+        [self.0, self.1, self.2, self.3].to_hsva(options)
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions) {
+        let mut components = [self.0, self.1, self.2, self.3];
+        components.set_hsva(hsva, options);
+        let [r, g, b, a] = components;
+        *self = (r, g, b, a);
+    }
+}
+
+impl ColorComponents for u32 {
+    fn to_hsva(&self, options: ColorOptions) -> Hsva {
+        if options.alpha {
+            hsva_from_srgba(self.to_be_bytes())
+        } else {
+            let [_, r, g, b] = self.to_be_bytes();
+            Hsva::from_srgb([r, g, b])
+        }
+    }
+
+    fn set_hsva(&mut self, hsva: Hsva, options: ColorOptions) {
+        *self = if options.alpha {
+            u32::from_be_bytes(hsva.to_srgba_unmultiplied())
+        } else {
+            let [r, g, b] = hsva.to_srgb();
+            u32::from_be_bytes([0, r, g, b])
+        };
+    }
+}
+
+/// Read-only color swatch for a field marked `#[enum2egui(color)]`.
+pub fn color_ui<T: ColorComponents>(value: &T, ui: &mut Ui, options: ColorOptions) {
+    color_swatch_ui(value.to_hsva(options), ui);
+}
+
+/// Color picker button for a field marked `#[enum2egui(color)]`.
+pub fn color_ui_mut<T: ColorComponents>(value: &mut T, ui: &mut Ui, options: ColorOptions) {
+    let mut hsva = value.to_hsva(options);
+    if color_edit_button_hsva(ui, &mut hsva, options.alpha_mode()).changed() {
+        value.set_hsva(hsva, options);
+    }
+}
+
+fn color_swatch_ui(color: impl Into<Color32>, ui: &mut Ui) {
+    show_color(ui, color, ui.spacing().interact_size);
+}
+
+impl GuiInspect for Color32 {
+    fn ui(&self, ui: &mut Ui) {
+        color_swatch_ui(*self, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        ui.color_edit_button_srgba(self);
+    }
+}
+
+impl GuiInspect for Rgba {
+    fn ui(&self, ui: &mut Ui) {
+        color_swatch_ui(*self, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        color_edit_button_rgba(ui, self, Alpha::BlendOrAdditive);
+    }
+}
+
+impl GuiInspect for Hsva {
+    fn ui(&self, ui: &mut Ui) {
+        color_swatch_ui(*self, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        color_edit_button_hsva(ui, self, Alpha::BlendOrAdditive);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPAQUE: ColorOptions = ColorOptions {
+        alpha: false,
+        hsva: false,
+    };
+    const ALPHA: ColorOptions = ColorOptions {
+        alpha: true,
+        hsva: false,
+    };
+
+    fn round_trip<T: ColorComponents + Clone>(value: &T, options: ColorOptions) -> T {
+        let mut result = value.clone();
+        result.set_hsva(value.to_hsva(options), options);
+        result
+    }
+
+    #[test]
+    fn byte_colors_round_trip() {
+        assert_eq!(round_trip(&[12u8, 34, 56], OPAQUE), [12, 34, 56]);
+        assert_eq!(round_trip(&[12u8, 34, 56, 78], ALPHA), [12, 34, 56, 78]);
+        assert_eq!(round_trip(&(12u8, 34u8, 56u8), OPAQUE), (12, 34, 56));
+        assert_eq!(
+            round_trip(&(12u8, 34u8, 56u8, 78u8), ALPHA),
+            (12, 34, 56, 78)
+        );
+    }
+
+    #[test]
+    fn float_colors_round_trip() {
+        let rgb = round_trip(&[0.25f32, 0.5, 0.75], OPAQUE);
+        let expected = [0.25, 0.5, 0.75];
+        assert!(rgb.iter().zip(expected).all(|(x, y)| (x - y).abs() < 1e-5));
+
+        let hsva = ColorOptions {
+            alpha: true,
+            hsva: true,
+        };
+        assert_eq!(
+            round_trip(&[0.1f32, 0.2, 0.3, 0.4], hsva),
+            [0.1, 0.2, 0.3, 0.4]
+        );
+    }
+
+    #[test]
+    fn packed_colors_round_trip() {
+        assert_eq!(round_trip(&0x123456u32, OPAQUE), 0x123456);
+        assert_eq!(round_trip(&0x12345678u32, ALPHA), 0x12345678);
+    }
+
+    #[test]
+    fn alpha_is_kept_without_the_alpha_option() {
+        let mut color = [12u8, 34, 56, 78];
+        color.set_hsva(hsva_from_srgba([90, 90, 90, 255]), OPAQUE);
+        assert_eq!(color, [90, 90, 90, 78]);
+
+        let mut color = [0.1f32, 0.2, 0.3, 0.4];
+        color.set_hsva(Hsva::new(0.0, 0.0, 1.0, 1.0), OPAQUE);
+        assert_eq!(color[3], 0.4);
+    }
+}
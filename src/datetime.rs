@@ -0,0 +1,526 @@
+use egui::{DragValue, Ui};
+
+/// A calendar date shared by the `chrono` and `time` editors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CivilDate {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+/// A time of day shared by the `chrono` and `time` editors.
+/// Sub-second precision is left to the caller to preserve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week with Monday as 0, using Sakamoto's method.
+fn weekday(date: CivilDate) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if date.month < 3 {
+        date.year - 1
+    } else {
+        date.year
+    };
+    let sunday_based = (year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + OFFSETS[date.month as usize - 1]
+        + date.day as i32)
+        .rem_euclid(7);
+    (sunday_based as u32 + 6) % 7
+}
+
+/// A button that opens a calendar popup, similar to egui_extras' `DatePickerButton`.
+/// Returns true if a different date was picked.
+fn date_picker_ui(date: &mut CivilDate, ui: &mut Ui) -> bool {
+    let original = *date;
+    let response = ui.button("📅").on_hover_text("Pick a date");
+    egui::Popup::from_toggle_button_response(&response)
+        .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside)
+        .show(|ui| calendar_ui(date, ui));
+    *date != original
+}
+
+fn calendar_ui(date: &mut CivilDate, ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.add(DragValue::new(&mut date.year));
+        egui::ComboBox::from_id_salt(ui.next_auto_id())
+            .selected_text(MONTHS[date.month as usize - 1])
+            .show_ui(ui, |ui| {
+                (1..=12).for_each(|month| {
+                    ui.selectable_value(&mut date.month, month, MONTHS[month as usize - 1]);
+                });
+            });
+    });
+    date.day = date.day.min(days_in_month(date.year, date.month));
+
+    let first = CivilDate { day: 1, ..*date };
+    let leading_blanks = weekday(first);
+    egui::Grid::new(ui.next_auto_id())
+        .num_columns(WEEKDAYS.len())
+        .show(ui, |ui| {
+            WEEKDAYS.iter().for_each(|weekday| {
+                ui.label(*weekday);
+            });
+            ui.end_row();
+
+            (0..leading_blanks).for_each(|_| {
+                ui.label("");
+            });
+            (1..=days_in_month(date.year, date.month)).for_each(|day| {
+                if ui
+                    .selectable_label(day == date.day, day.to_string())
+                    .clicked()
+                {
+                    date.day = day;
+                }
+                if (leading_blanks + day).is_multiple_of(7) {
+                    ui.end_row();
+                }
+            });
+        });
+}
+
+/// Hour, minute and second spinners. Returns true if any of them changed.
+fn time_of_day_ui(time: &mut TimeOfDay, ui: &mut Ui) -> bool {
+    let original = *time;
+    ui.horizontal(|ui| {
+        ui.spacing_mut().item_spacing.x = 2.0;
+        ui.add(DragValue::new(&mut time.hour).range(0..=23));
+        ui.label(":");
+        ui.add(DragValue::new(&mut time.minute).range(0..=59));
+        ui.label(":");
+        ui.add(DragValue::new(&mut time.second).range(0..=59));
+    });
+    *time != original
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{CivilDate, TimeOfDay, date_picker_ui, time_of_day_ui};
+    use crate::{GuiInspect, parse_with_ui_mut};
+    use chrono::{
+        DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone, Timelike,
+    };
+    use egui::Ui;
+    use std::fmt::Display;
+
+    fn civil_date(date: &NaiveDate) -> CivilDate {
+        CivilDate {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+        }
+    }
+
+    fn naive_date(date: CivilDate) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(date.year, date.month, date.day)
+    }
+
+    fn time_of_day(time: &NaiveTime) -> TimeOfDay {
+        TimeOfDay {
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+
+    fn naive_time(time: TimeOfDay, nanosecond: u32) -> Option<NaiveTime> {
+        NaiveTime::from_hms_nano_opt(time.hour, time.minute, time.second, nanosecond)
+    }
+
+    /// Date picker and time spinners for a date-time without a timezone.
+    fn naive_date_time_ui_mut(value: &mut NaiveDateTime, ui: &mut Ui) -> bool {
+        let mut date = civil_date(&value.date());
+        let mut time = time_of_day(&value.time());
+        let date_changed = date_picker_ui(&mut date, ui);
+        let time_changed = time_of_day_ui(&mut time, ui);
+        if !(date_changed || time_changed) {
+            return false;
+        }
+        match (naive_date(date), naive_time(time, value.nanosecond())) {
+            (Some(date), Some(time)) => {
+                *value = date.and_time(time);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    impl GuiInspect for NaiveDate {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(self.to_string());
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(self, ui, NaiveDate::to_string, str::parse);
+                let mut date = civil_date(self);
+                if date_picker_ui(&mut date, ui)
+                    && let Some(date) = naive_date(date)
+                {
+                    *self = date;
+                }
+            });
+        }
+    }
+
+    impl GuiInspect for NaiveTime {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(self.format("%H:%M:%S").to_string());
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let mut time = time_of_day(self);
+            if time_of_day_ui(&mut time, ui)
+                && let Some(time) = naive_time(time, self.nanosecond())
+            {
+                *self = time;
+            }
+        }
+    }
+
+    // `NaiveDateTime` displays with a space instead of the ISO-8601 `T`,
+    // so it is formatted explicitly to round-trip through the text box.
+    const ISO_8601: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    impl GuiInspect for NaiveDateTime {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(self.format(ISO_8601).to_string());
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(
+                    self,
+                    ui,
+                    |value| value.format(ISO_8601).to_string(),
+                    str::parse,
+                );
+                naive_date_time_ui_mut(self, ui);
+            });
+        }
+    }
+
+    impl<Tz> GuiInspect for DateTime<Tz>
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(self.to_rfc3339_opts(SecondsFormat::AutoSi, true));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let timezone = self.timezone();
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(
+                    self,
+                    ui,
+                    |value| value.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+                    |text| {
+                        DateTime::parse_from_rfc3339(text)
+                            .map(|value| value.with_timezone(&timezone))
+                    },
+                );
+
+                let mut local = self.naive_local();
+                if naive_date_time_ui_mut(&mut local, ui)
+                    && let Some(value) = timezone.from_local_datetime(&local).single()
+                {
+                    *self = value;
+                }
+
+                ui.weak(self.offset().to_string());
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn dates_round_trip() {
+            let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+            assert_eq!(naive_date(civil_date(&date)), Some(date));
+        }
+
+        #[test]
+        fn invalid_dates_are_rejected() {
+            let date = CivilDate {
+                year: 2023,
+                month: 2,
+                day: 29,
+            };
+            assert_eq!(naive_date(date), None);
+        }
+
+        #[test]
+        fn times_keep_their_nanoseconds() {
+            let time = NaiveTime::from_hms_nano_opt(23, 59, 58, 123_456_789).unwrap();
+            assert_eq!(
+                naive_time(time_of_day(&time), time.nanosecond()),
+                Some(time)
+            );
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{CivilDate, TimeOfDay, date_picker_ui, time_of_day_ui};
+    use crate::{GuiInspect, parse_with_ui_mut};
+    use egui::Ui;
+    use time::{
+        Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
+        format_description::well_known::{Iso8601, Rfc3339},
+    };
+
+    fn civil_date(date: &Date) -> CivilDate {
+        CivilDate {
+            year: date.year(),
+            month: u8::from(date.month()).into(),
+            day: date.day().into(),
+        }
+    }
+
+    fn date(date: CivilDate) -> Option<Date> {
+        let month = Month::try_from(u8::try_from(date.month).ok()?).ok()?;
+        let day = u8::try_from(date.day).ok()?;
+        Date::from_calendar_date(date.year, month, day).ok()
+    }
+
+    fn time_of_day(time: &Time) -> TimeOfDay {
+        TimeOfDay {
+            hour: time.hour().into(),
+            minute: time.minute().into(),
+            second: time.second().into(),
+        }
+    }
+
+    fn time(time: TimeOfDay, nanosecond: u32) -> Option<Time> {
+        Time::from_hms_nano(
+            u8::try_from(time.hour).ok()?,
+            u8::try_from(time.minute).ok()?,
+            u8::try_from(time.second).ok()?,
+            nanosecond,
+        )
+        .ok()
+    }
+
+    /// Date picker and time spinners for a date-time without an offset.
+    fn primitive_date_time_ui_mut(value: &mut PrimitiveDateTime, ui: &mut Ui) -> bool {
+        let mut civil = civil_date(&value.date());
+        let mut time_of_day = time_of_day(&value.time());
+        let date_changed = date_picker_ui(&mut civil, ui);
+        let time_changed = time_of_day_ui(&mut time_of_day, ui);
+        if !(date_changed || time_changed) {
+            return false;
+        }
+        match (date(civil), time(time_of_day, value.nanosecond())) {
+            (Some(date), Some(time)) => {
+                *value = PrimitiveDateTime::new(date, time);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn format_iso_8601(value: &impl TimeFormat) -> String {
+        value.format_iso_8601().unwrap_or_default()
+    }
+
+    /// The `time` types format through fallible methods with no shared trait,
+    /// so this gives them a common shape for the text editor.
+    trait TimeFormat {
+        fn format_iso_8601(&self) -> Option<String>;
+    }
+
+    impl TimeFormat for Date {
+        fn format_iso_8601(&self) -> Option<String> {
+            self.format(&Iso8601::DATE).ok()
+        }
+    }
+
+    impl TimeFormat for PrimitiveDateTime {
+        fn format_iso_8601(&self) -> Option<String> {
+            self.format(&Iso8601::DATE_TIME).ok()
+        }
+    }
+
+    impl TimeFormat for OffsetDateTime {
+        fn format_iso_8601(&self) -> Option<String> {
+            self.format(&Rfc3339).ok()
+        }
+    }
+
+    impl GuiInspect for Date {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(format_iso_8601(self));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(self, ui, format_iso_8601, |text| {
+                    Date::parse(text, &Iso8601::DATE)
+                });
+                let mut civil = civil_date(self);
+                if date_picker_ui(&mut civil, ui)
+                    && let Some(date) = date(civil)
+                {
+                    *self = date;
+                }
+            });
+        }
+    }
+
+    impl GuiInspect for Time {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(format!(
+                "{:02}:{:02}:{:02}",
+                self.hour(),
+                self.minute(),
+                self.second()
+            ));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let mut time_of_day = time_of_day(self);
+            if time_of_day_ui(&mut time_of_day, ui)
+                && let Some(time) = time(time_of_day, self.nanosecond())
+            {
+                *self = time;
+            }
+        }
+    }
+
+    impl GuiInspect for PrimitiveDateTime {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(format_iso_8601(self));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(self, ui, format_iso_8601, |text| {
+                    PrimitiveDateTime::parse(text, &Iso8601::DATE_TIME)
+                });
+                primitive_date_time_ui_mut(self, ui);
+            });
+        }
+    }
+
+    impl GuiInspect for OffsetDateTime {
+        fn ui(&self, ui: &mut Ui) {
+            ui.label(format_iso_8601(self));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                parse_with_ui_mut(self, ui, format_iso_8601, |text| {
+                    OffsetDateTime::parse(text, &Rfc3339)
+                });
+
+                let offset = self.offset();
+                let mut local = PrimitiveDateTime::new(self.date(), self.time());
+                if primitive_date_time_ui_mut(&mut local, ui) {
+                    *self = local.assume_offset(offset);
+                }
+
+                ui.weak(offset.to_string());
+            });
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn dates_round_trip() {
+            let civil = CivilDate {
+                year: 2024,
+                month: 2,
+                day: 29,
+            };
+            let converted = date(civil).unwrap();
+            assert_eq!(civil_date(&converted), civil);
+        }
+
+        #[test]
+        fn invalid_dates_are_rejected() {
+            let civil = CivilDate {
+                year: 2023,
+                month: 13,
+                day: 1,
+            };
+            assert_eq!(date(civil), None);
+        }
+
+        #[test]
+        fn times_keep_their_nanoseconds() {
+            let converted = Time::from_hms_nano(23, 59, 58, 123_456_789).unwrap();
+            let round_tripped = time(time_of_day(&converted), converted.nanosecond());
+            assert_eq!(round_tripped, Some(converted));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(2023, 4), 30);
+        assert_eq!(days_in_month(2023, 12), 31);
+    }
+
+    #[test]
+    fn weekdays_start_on_monday() {
+        let date = |year, month, day| CivilDate { year, month, day };
+        assert_eq!(weekday(date(2024, 1, 1)), 0);
+        assert_eq!(weekday(date(2000, 2, 29)), 1);
+        assert_eq!(weekday(date(1970, 1, 1)), 3);
+        assert_eq!(weekday(date(2023, 12, 31)), 6);
+    }
+}
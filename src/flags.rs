@@ -0,0 +1,290 @@
+#[cfg(feature = "enumset")]
+use crate::GuiInspect;
+use egui::Ui;
+use std::{collections::HashSet, hash::BuildHasher, hash::Hash};
+
+/// The selectable variants of a C-like enum, with their labels.
+///
+/// Derived for `#[derive(Gui)]` enums whose variants are all units,
+/// leaving out `#[enum2egui(skip)]` variants.
+pub trait UnitVariants: Sized {
+    fn unit_variants() -> Vec<(Self, &'static str)>;
+}
+
+/// A set of named flags that can be toggled one at a time.
+///
+/// Implemented for `Vec<E>` and `HashSet<E>` of derived C-like enums, which is what
+/// fields marked `#[enum2egui(flags)]` are edited through.
+pub trait FlagSet {
+    /// Each flag's label and whether it is set.
+    fn flags(&self) -> Vec<(&'static str, bool)>;
+
+    /// Sets each flag, in the order returned by [`FlagSet::flags`].
+    fn set_flags(&mut self, states: &[bool]);
+}
+
+impl<E: UnitVariants + PartialEq> FlagSet for Vec<E> {
+    fn flags(&self) -> Vec<(&'static str, bool)> {
+        E::unit_variants()
+            .into_iter()
+            .map(|(variant, label)| (label, self.contains(&variant)))
+            .collect()
+    }
+
+    /// Rebuilds the listed variants in variant order, so none is held twice.
+    /// Elements that aren't listed, such as skipped variants, are kept after them.
+    fn set_flags(&mut self, states: &[bool]) {
+        let variants = E::unit_variants();
+        let unlisted = std::mem::take(self)
+            .into_iter()
+            .filter(|item| !variants.iter().any(|(variant, _)| variant == item))
+            .collect::<Vec<_>>();
+        *self = variants
+            .into_iter()
+            .zip(states)
+            .filter(|(_, set)| **set)
+            .map(|((variant, _), _)| variant)
+            .chain(unlisted)
+            .collect();
+    }
+}
+
+impl<E: UnitVariants + Eq + Hash, S: BuildHasher> FlagSet for HashSet<E, S> {
+    fn flags(&self) -> Vec<(&'static str, bool)> {
+        E::unit_variants()
+            .into_iter()
+            .map(|(variant, label)| (label, self.contains(&variant)))
+            .collect()
+    }
+
+    fn set_flags(&mut self, states: &[bool]) {
+        E::unit_variants()
+            .into_iter()
+            .zip(states)
+            .for_each(|((variant, _), set)| {
+                if *set {
+                    self.insert(variant);
+                } else {
+                    self.remove(&variant);
+                }
+            });
+    }
+}
+
+/// Read-only view of a flag set as its set flags joined by `|`.
+pub fn flags_ui<S: FlagSet>(set: &S, ui: &mut Ui) {
+    set_flags_label_ui(set.flags(), ui);
+}
+
+/// Editable view of a flag set with a checkbox per flag.
+pub fn flags_ui_mut<S: FlagSet>(set: &mut S, ui: &mut Ui) {
+    let mut flags = set.flags();
+    if checkboxes_ui_mut(&mut flags, ui) {
+        let states = flags.iter().map(|(_, set)| *set).collect::<Vec<_>>();
+        set.set_flags(&states);
+    }
+}
+
+fn set_flags_label_ui<L: AsRef<str>>(flags: Vec<(L, bool)>, ui: &mut Ui) {
+    let labels = flags
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(label, _)| label.as_ref())
+        .collect::<Vec<_>>();
+    if labels.is_empty() {
+        ui.weak("None");
+    } else {
+        ui.label(labels.join(" | "));
+    }
+}
+
+/// A checkbox per flag beneath All and None buttons. Returns true if any flag changed.
+fn checkboxes_ui_mut<L: AsRef<str>>(flags: &mut [(L, bool)], ui: &mut Ui) -> bool {
+    let mut changed = false;
+    ui.group(|ui| {
+        ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                let all = flags.iter().all(|(_, set)| *set);
+                let none = flags.iter().all(|(_, set)| !*set);
+                if ui.add_enabled(!all, egui::Button::new("All")).clicked() {
+                    flags.iter_mut().for_each(|(_, set)| *set = true);
+                    changed = true;
+                }
+                if ui.add_enabled(!none, egui::Button::new("None")).clicked() {
+                    flags.iter_mut().for_each(|(_, set)| *set = false);
+                    changed = true;
+                }
+            });
+            ui.separator();
+            flags.iter_mut().for_each(|(label, set)| {
+                changed |= ui.checkbox(set, label.as_ref()).changed();
+            });
+        });
+    });
+    changed
+}
+
+/// Read-only view of a `bitflags` type as its set flags joined by `|`.
+#[cfg(feature = "bitflags")]
+pub fn bitflags_ui<F: bitflags::Flags>(flags: &F, ui: &mut Ui) {
+    set_flags_label_ui(named_bitflags(flags), ui);
+}
+
+/// Editable view of a `bitflags` type with a checkbox per named flag.
+/// Bits that don't belong to a named flag are left untouched.
+#[cfg(feature = "bitflags")]
+pub fn bitflags_ui_mut<F: bitflags::Flags>(flags: &mut F, ui: &mut Ui) {
+    let mut states = named_bitflags(flags);
+    if checkboxes_ui_mut(&mut states, ui) {
+        F::FLAGS
+            .iter()
+            .filter(|flag| flag.is_named())
+            .zip(states)
+            .for_each(|(flag, (_, set))| {
+                flags.set(F::from_bits_retain(flag.value().bits()), set);
+            });
+    }
+}
+
+#[cfg(feature = "bitflags")]
+fn named_bitflags<F: bitflags::Flags>(flags: &F) -> Vec<(&'static str, bool)> {
+    F::FLAGS
+        .iter()
+        .filter(|flag| flag.is_named())
+        .map(|flag| {
+            let bits = F::from_bits_retain(flag.value().bits());
+            (flag.name(), flags.contains(bits))
+        })
+        .collect()
+}
+
+/// Implements `GuiInspect` for types declared with the `bitflags!` macro,
+/// which can't be covered by a blanket impl.
+///
+/// ```ignore
+/// bitflags::bitflags! {
+///     pub struct Permissions: u8 {
+///         const READ = 1;
+///         const WRITE = 1 << 1;
+///     }
+/// }
+///
+/// enum2egui::impl_gui_for_bitflags!(Permissions);
+/// ```
+#[cfg(feature = "bitflags")]
+#[macro_export]
+macro_rules! impl_gui_for_bitflags {
+    ($($t:ty),* $(,)?) => ($(
+        impl $crate::GuiInspect for $t {
+            fn ui(&self, ui: &mut $crate::egui::Ui) {
+                $crate::bitflags_ui(self, ui);
+            }
+
+            fn ui_mut(&mut self, ui: &mut $crate::egui::Ui) {
+                $crate::bitflags_ui_mut(self, ui);
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "enumset")]
+impl<T: enumset::EnumSetType + std::fmt::Debug> GuiInspect for enumset::EnumSet<T> {
+    fn ui(&self, ui: &mut Ui) {
+        set_flags_label_ui(enumset_flags(self), ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let mut states = enumset_flags(self);
+        if checkboxes_ui_mut(&mut states, ui) {
+            *self = enumset::EnumSet::<T>::all()
+                .iter()
+                .zip(states)
+                .filter(|(_, (_, set))| *set)
+                .map(|(variant, _)| variant)
+                .collect();
+        }
+    }
+}
+
+/// Every variant of the enum, labelled with its `Debug` representation.
+#[cfg(feature = "enumset")]
+fn enumset_flags<T: enumset::EnumSetType + std::fmt::Debug>(
+    set: &enumset::EnumSet<T>,
+) -> Vec<(String, bool)> {
+    enumset::EnumSet::<T>::all()
+        .iter()
+        .map(|variant| (format!("{variant:?}"), set.contains(variant)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Permission {
+        Read,
+        Write,
+        Execute,
+        /// Left out of the listed variants, like a skipped variant.
+        Legacy,
+    }
+
+    impl UnitVariants for Permission {
+        fn unit_variants() -> Vec<(Self, &'static str)> {
+            vec![
+                (Permission::Read, "Read"),
+                (Permission::Write, "Write"),
+                (Permission::Execute, "Execute"),
+            ]
+        }
+    }
+
+    #[test]
+    fn vec_flags_follow_variant_order() {
+        let set = vec![Permission::Execute, Permission::Read];
+        assert_eq!(
+            set.flags(),
+            [("Read", true), ("Write", false), ("Execute", true)]
+        );
+    }
+
+    #[test]
+    fn vec_set_flags_rebuilds_in_variant_order() {
+        let mut set = vec![Permission::Execute, Permission::Read, Permission::Read];
+        set.set_flags(&[true, true, false]);
+        assert_eq!(set, [Permission::Read, Permission::Write]);
+    }
+
+    #[test]
+    fn vec_set_flags_keeps_unlisted_variants() {
+        let mut set = vec![Permission::Legacy, Permission::Write];
+        set.set_flags(&[true, false, false]);
+        assert_eq!(set, [Permission::Read, Permission::Legacy]);
+    }
+
+    #[test]
+    fn hash_set_set_flags_keeps_unlisted_variants() {
+        let mut set = HashSet::from([Permission::Legacy, Permission::Write]);
+        set.set_flags(&[true, false, true]);
+        assert_eq!(
+            set,
+            HashSet::from([Permission::Read, Permission::Execute, Permission::Legacy])
+        );
+    }
+
+    #[cfg(feature = "bitflags")]
+    #[test]
+    fn named_bitflags_ignore_unnamed_bits() {
+        bitflags::bitflags! {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            struct Mode: u8 {
+                const READ = 1;
+                const WRITE = 1 << 1;
+            }
+        }
+
+        let mode = Mode::from_bits_retain(Mode::WRITE.bits() | 1 << 7);
+        assert_eq!(named_bitflags(&mode), [("READ", false), ("WRITE", true)]);
+    }
+}
@@ -1,12 +1,116 @@
 #![allow(non_snake_case)]
 
 use egui::{Ui, widgets::DragValue};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque},
+    ffi::OsString,
+    fmt::Display,
+    marker::PhantomData,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
+    num::{NonZero, Wrapping},
+    ops::{Range, RangeInclusive},
+    path::PathBuf,
+    rc::Rc,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError, RwLock, TryLockError},
+    time::Duration,
+};
 
 pub use egui;
 
 pub trait GuiInspect {
     fn ui(&self, ui: &mut Ui);
     fn ui_mut(&mut self, ui: &mut Ui);
+
+    /// Edits the value through a shared reference, as needed behind `Rc` or `Arc`.
+    /// Only interior-mutable types such as `RefCell` and `Mutex` can do this,
+    /// so the default falls back to the read-only `ui`.
+    fn ui_shared(&self, ui: &mut Ui) {
+        self.ui(ui);
+    }
+
+    /// Whether the value is a scalar, such as a number, that fits in a row beside others.
+    /// Small arrays of inline values are laid out horizontally.
+    fn inline() -> bool
+    where
+        Self: Sized,
+    {
+        false
+    }
+}
+
+/// Nesting depth beyond which derived types are collapsed unless
+/// a different limit is set with [`set_max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 8;
+
+fn depth_id() -> egui::Id {
+    egui::Id::new("enum2egui_depth")
+}
+
+fn max_depth_id() -> egui::Id {
+    egui::Id::new("enum2egui_max_depth")
+}
+
+/// Sets how many derived types may be nested before the rest of the tree
+/// is collapsed behind an "Expand" button.
+pub fn set_max_depth(ctx: &egui::Context, max_depth: usize) {
+    ctx.data_mut(|data| data.insert_temp(max_depth_id(), max_depth));
+}
+
+pub fn max_depth(ctx: &egui::Context) -> usize {
+    ctx.data(|data| data.get_temp(max_depth_id()))
+        .unwrap_or(DEFAULT_MAX_DEPTH)
+}
+
+/// Renders one level of a nested data structure, tracking the current depth
+/// so that self-referential types don't render unboundedly deep.
+///
+/// Derived impls wrap their contents in this. Once the maximum depth is reached,
+/// the contents are hidden behind an "Expand" button, and an expanded subtree
+/// gets a fresh depth budget of its own.
+///
+/// A field's picker override from [`crate::with_picker`] doesn't reach into the contents.
+pub fn nested_ui(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui)) {
+    crate::picker::without_picker(ui, |ui| depth_limited_ui(ui, add_contents));
+}
+
+fn depth_limited_ui(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui)) {
+    let depth = ui
+        .data(|data| data.get_temp::<usize>(depth_id()))
+        .unwrap_or_default();
+
+    if depth < max_depth(ui.ctx()) {
+        with_depth(ui, depth + 1, add_contents);
+        return;
+    }
+
+    let expanded_id = ui.next_auto_id().with("expanded");
+    let mut expanded = ui
+        .data(|data| data.get_temp::<bool>(expanded_id))
+        .unwrap_or_default();
+
+    ui.vertical(|ui| {
+        let label = if expanded { "Collapse" } else { "Expand" };
+        if ui.button(label).clicked() {
+            expanded = !expanded;
+            ui.data_mut(|data| data.insert_temp(expanded_id, expanded));
+        }
+
+        if expanded {
+            with_depth(ui, 1, add_contents);
+        }
+    });
+}
+
+fn with_depth(ui: &mut Ui, depth: usize, add_contents: impl FnOnce(&mut Ui)) {
+    let previous = ui
+        .data(|data| data.get_temp::<usize>(depth_id()))
+        .unwrap_or_default();
+    ui.data_mut(|data| data.insert_temp(depth_id(), depth));
+    add_contents(ui);
+    ui.data_mut(|data| data.insert_temp(depth_id(), previous));
 }
 
 macro_rules! impl_gui_for_primitive {
@@ -19,11 +123,15 @@ macro_rules! impl_gui_for_primitive {
             fn ui_mut(&mut self, ui: &mut Ui) {
                 ui.add(DragValue::new(self));
             }
+
+            fn inline() -> bool {
+                true
+            }
         }
     )*)
 }
 
-impl_gui_for_primitive!(i8 i16 i32 i64 u8 u16 u32 u64 f32 f64);
+impl_gui_for_primitive!(i8 i16 i32 u8 u16 u32 f32 f64);
 
 impl GuiInspect for bool {
     fn ui(&self, ui: &mut Ui) {
@@ -33,6 +141,10 @@ impl GuiInspect for bool {
     fn ui_mut(&mut self, ui: &mut Ui) {
         ui.checkbox(self, "");
     }
+
+    fn inline() -> bool {
+        true
+    }
 }
 
 impl GuiInspect for String {
@@ -45,9 +157,241 @@ impl GuiInspect for String {
     }
 }
 
+/// Presentation options for `String` fields, set with field attributes
+/// like `#[enum2egui(multiline, rows = 4)]` or `#[enum2egui(password)]`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TextOptions {
+    pub multiline: bool,
+    pub rows: Option<usize>,
+    pub password: bool,
+    pub hint: Option<&'static str>,
+    pub max_chars: Option<usize>,
+    /// Monospace multiline editor for source code.
+    pub code: bool,
+    /// Language of the source code, shown beside a code editor.
+    pub language: Option<&'static str>,
+}
+
+impl TextOptions {
+    fn is_multiline(&self) -> bool {
+        self.multiline || self.code || self.rows.is_some()
+    }
+}
+
+/// Read-only view of a string that masks passwords and uses
+/// a monospace font for code.
+pub fn text_ui(text: &str, ui: &mut Ui, options: TextOptions) {
+    if options.password {
+        ui.label("•".repeat(text.chars().count()));
+    } else if options.code {
+        ui.label(egui::RichText::new(text).monospace());
+    } else {
+        ui.label(text);
+    }
+}
+
+/// Editable view of a string using the given `options`.
+pub fn text_ui_mut(text: &mut String, ui: &mut Ui, options: TextOptions) {
+    ui.vertical(|ui| {
+        if let Some(language) = options.language {
+            ui.weak(language);
+        }
+
+        let mut text_edit = if options.is_multiline() {
+            egui::TextEdit::multiline(text)
+        } else {
+            egui::TextEdit::singleline(text)
+        }
+        .password(options.password);
+
+        if options.code {
+            text_edit = text_edit.code_editor();
+        }
+        if let Some(rows) = options.rows {
+            text_edit = text_edit.desired_rows(rows);
+        }
+        if let Some(hint) = options.hint {
+            text_edit = text_edit.hint_text(hint);
+        }
+        if let Some(max_chars) = options.max_chars {
+            text_edit = text_edit.char_limit(max_chars);
+        }
+
+        ui.add(text_edit);
+    });
+}
+
+impl GuiInspect for char {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(format!("{self}"));
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let mut text = self.to_string();
+        let response = ui.add(egui::TextEdit::singleline(&mut text).desired_width(16.0));
+        if response.changed()
+            && let Some(value) = text.chars().find(|value| value != self)
+        {
+            *self = value;
+        }
+    }
+
+    fn inline() -> bool {
+        true
+    }
+}
+
+macro_rules! impl_gui_for_str {
+    ($($t:ty),*) => ($(
+        impl GuiInspect for $t {
+            fn ui(&self, ui: &mut Ui) {
+                let text: &str = self;
+                ui.label(text);
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                self.ui(ui);
+            }
+        }
+    )*)
+}
+
+// Borrowed and shared strings cannot be edited in place, so they are always read-only.
+impl_gui_for_str!(str, &str, std::borrow::Cow<'_, str>);
+
+impl<T: GuiInspect + ?Sized> GuiInspect for Box<T> {
+    fn ui(&self, ui: &mut Ui) {
+        (**self).ui(ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        (**self).ui_mut(ui);
+    }
+
+    fn ui_shared(&self, ui: &mut Ui) {
+        (**self).ui_shared(ui);
+    }
+}
+
+macro_rules! impl_gui_for_shared {
+    ($($t:ident)*) => ($(
+        impl<T: GuiInspect + ?Sized> GuiInspect for $t<T> {
+            fn ui(&self, ui: &mut Ui) {
+                (**self).ui(ui);
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                (**self).ui_shared(ui);
+            }
+
+            fn ui_shared(&self, ui: &mut Ui) {
+                (**self).ui_shared(ui);
+            }
+        }
+    )*)
+}
+
+// Shared pointers only hand out `&T`, so their contents are read-only
+// unless they wrap an interior-mutable type like `RefCell` or `Mutex`.
+impl_gui_for_shared!(Rc Arc);
+
+impl<T: GuiInspect> GuiInspect for RefCell<T> {
+    fn ui(&self, ui: &mut Ui) {
+        match self.try_borrow() {
+            Ok(value) => value.ui(ui),
+            Err(_) => borrowed_ui(ui),
+        }
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.get_mut().ui_mut(ui);
+    }
+
+    fn ui_shared(&self, ui: &mut Ui) {
+        match self.try_borrow_mut() {
+            Ok(mut value) => value.ui_mut(ui),
+            Err(_) => borrowed_ui(ui),
+        }
+    }
+}
+
+impl<T: GuiInspect + Copy> GuiInspect for Cell<T> {
+    fn ui(&self, ui: &mut Ui) {
+        self.get().ui(ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.get_mut().ui_mut(ui);
+    }
+
+    fn ui_shared(&self, ui: &mut Ui) {
+        let mut value = self.get();
+        value.ui_mut(ui);
+        self.set(value);
+    }
+}
+
+impl<T: GuiInspect> GuiInspect for Mutex<T> {
+    fn ui(&self, ui: &mut Ui) {
+        match self.try_lock() {
+            Ok(value) => value.ui(ui),
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().ui(ui),
+            Err(TryLockError::WouldBlock) => locked_ui(ui),
+        }
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .ui_mut(ui);
+    }
+
+    fn ui_shared(&self, ui: &mut Ui) {
+        match self.try_lock() {
+            Ok(mut value) => value.ui_mut(ui),
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().ui_mut(ui),
+            Err(TryLockError::WouldBlock) => locked_ui(ui),
+        }
+    }
+}
+
+impl<T: GuiInspect> GuiInspect for RwLock<T> {
+    fn ui(&self, ui: &mut Ui) {
+        match self.try_read() {
+            Ok(value) => value.ui(ui),
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().ui(ui),
+            Err(TryLockError::WouldBlock) => locked_ui(ui),
+        }
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .ui_mut(ui);
+    }
+
+    fn ui_shared(&self, ui: &mut Ui) {
+        match self.try_write() {
+            Ok(mut value) => value.ui_mut(ui),
+            Err(TryLockError::Poisoned(poisoned)) => poisoned.into_inner().ui_mut(ui),
+            Err(TryLockError::WouldBlock) => locked_ui(ui),
+        }
+    }
+}
+
+fn borrowed_ui(ui: &mut Ui) {
+    ui.weak("Borrowed")
+        .on_hover_text("The value is already borrowed elsewhere");
+}
+
+fn locked_ui(ui: &mut Ui) {
+    ui.weak("Locked")
+        .on_hover_text("The lock is held by another thread");
+}
+
 impl<T> GuiInspect for Option<T>
 where
-    T: Default + GuiInspect,
+    T: Default + GuiInspect + 'static,
 {
     fn ui(&self, ui: &mut Ui) {
         if let Some(value) = self {
@@ -58,14 +402,106 @@ where
     }
 
     fn ui_mut(&mut self, ui: &mut Ui) {
-        let mut checked = self.is_some();
-        ui.checkbox(&mut checked, "");
+        option_ui_mut(self, ui, OptionStyle::default());
+    }
+}
 
-        match (checked, self.as_mut()) {
-            (true, Some(value)) => value.ui_mut(ui),
-            (true, None) => *self = Some(T::default()),
-            (false, _) => *self = None,
+/// How an `Option<T>` editor switches between `Some` and `None`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OptionStyle {
+    /// A "Some" checkbox beside the value.
+    #[default]
+    Checkbox,
+    /// A "Set" button while `None`, and a "Clear" button beside the value.
+    Button,
+}
+
+thread_local! {
+    /// Values kept between frames by editors, such as a cleared `Option<T>`, keyed by widget id.
+    /// Kept outside egui memory, whose temp data needs `T: Clone + Send + Sync`.
+    static STASH: RefCell<std::collections::HashMap<egui::Id, Box<dyn Any>>> = RefCell::default();
+}
+
+fn stash<T: 'static>(id: egui::Id, value: T) {
+    STASH.with_borrow_mut(|stash| stash.insert(id, Box::new(value)));
+}
+
+fn take_stashed<T: 'static>(id: egui::Id) -> Option<T> {
+    let value = STASH.with_borrow_mut(|stash| stash.remove(&id))?;
+    value.downcast().ok().map(|value| *value)
+}
+
+/// Editable view of an `Option<T>`, generated for fields marked `#[enum2egui(option_style = "...")]`
+/// and used by the `GuiInspect` impl for `Option<T>` with the default style.
+///
+/// Clearing the option stashes the previous value, so setting it again
+/// restores that value instead of `T::default()`.
+pub fn option_ui_mut<T>(value: &mut Option<T>, ui: &mut Ui, style: OptionStyle)
+where
+    T: Default + GuiInspect + 'static,
+{
+    let stash_id = ui.next_auto_id().with("option");
+    let mut checked = value.is_some();
+    ui.horizontal(|ui| {
+        match style {
+            OptionStyle::Checkbox => {
+                ui.checkbox(&mut checked, "Some");
+            }
+            OptionStyle::Button => {
+                let label = if checked { "Clear" } else { "Set" };
+                if ui.button(label).clicked() {
+                    checked = !checked;
+                }
+            }
         }
+
+        match (checked, value.as_mut()) {
+            (true, Some(inner)) => inner.ui_mut(ui),
+            (true, None) => {
+                *value = Some(take_stashed(stash_id).unwrap_or_default());
+            }
+            (false, Some(_)) => {
+                if let Some(previous) = value.take() {
+                    stash(stash_id, previous);
+                }
+            }
+            (false, None) => {}
+        }
+    });
+}
+
+impl<T, E> GuiInspect for Result<T, E>
+where
+    T: Default + GuiInspect,
+    E: Default + GuiInspect,
+{
+    fn ui(&self, ui: &mut Ui) {
+        ui.horizontal(|ui| match self {
+            Ok(value) => {
+                ui.label("Ok");
+                value.ui(ui);
+            }
+            Err(error) => {
+                ui.label("Err");
+                error.ui(ui);
+            }
+        });
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui.radio(self.is_ok(), "Ok").clicked() && self.is_err() {
+                *self = Ok(T::default());
+            }
+            if ui.radio(self.is_err(), "Err").clicked() && self.is_ok() {
+                *self = Err(E::default());
+            }
+
+            match self {
+                Ok(value) => value.ui_mut(ui),
+                Err(error) => error.ui_mut(ui),
+            }
+        });
     }
 }
 
@@ -77,63 +513,539 @@ macro_rules! impl_large_numerics {
             }
 
             fn ui_mut(&mut self, ui: &mut Ui) {
-                let mut text = format!("{self}");
-                ui.text_edit_singleline(&mut text);
-                if let Ok(value) = text.parse() {
-                    *self = value;
-                }
+                let exact = i64::try_from(*self)
+                    .ok()
+                    .filter(|value| value.abs() <= MAX_EXACT_INTEGER);
+                let Some(mut value) = exact else {
+                    parse_ui_mut(self, ui);
+                    return;
+                };
+                let min = i64::try_from(<$t>::MIN)
+                    .unwrap_or(i64::MIN)
+                    .max(-MAX_EXACT_INTEGER);
+                let max = i64::try_from(<$t>::MAX)
+                    .unwrap_or(i64::MAX)
+                    .min(MAX_EXACT_INTEGER);
+                ui.add(DragValue::new(&mut value).range(min..=max));
+                *self = <$t>::try_from(value).unwrap_or(*self);
+            }
+
+            fn inline() -> bool {
+                true
             }
         }
     )*)
 }
 
-impl_large_numerics!(u128 i128 usize);
+/// The largest magnitude up to which `f64`, and so `DragValue`, holds every integer exactly.
+const MAX_EXACT_INTEGER: i64 = 1 << 53;
+
+/// Edits any `FromStr + Display` value through a text box.
+///
+/// The text being typed is kept in egui memory while the box has focus,
+/// so intermediate states like `-` or an empty field survive between frames.
+/// Input that fails to parse is shown in red with the parse error as a tooltip,
+/// and `value` is only updated once the text parses successfully.
+pub fn parse_ui_mut<T>(value: &mut T, ui: &mut Ui)
+where
+    T: FromStr + Display,
+    T::Err: Display,
+{
+    parse_with_ui_mut(value, ui, T::to_string, str::parse);
+}
+
+/// Like [`parse_ui_mut`], but with custom conversions to and from text
+/// for types whose `Display` and `FromStr` impls don't round-trip or don't exist.
+pub fn parse_with_ui_mut<T, E: Display>(
+    value: &mut T,
+    ui: &mut Ui,
+    format: impl Fn(&T) -> String,
+    parse: impl Fn(&str) -> Result<T, E>,
+) {
+    let id = ui.next_auto_id();
+    let mut text = ui
+        .data(|data| data.get_temp::<String>(id))
+        .unwrap_or_else(|| format(value));
+
+    let error = parse(&text).err().map(|error| error.to_string());
+    let text_color = error.as_ref().map(|_| ui.visuals().error_fg_color);
+
+    let mut response = ui.add(
+        egui::TextEdit::singleline(&mut text)
+            .id(id)
+            .text_color_opt(text_color),
+    );
+    if let Some(error) = error {
+        response = response.on_hover_text(error);
+    }
+
+    if response.changed()
+        && let Ok(parsed) = parse(&text)
+    {
+        *value = parsed;
+    }
+
+    if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(id, text));
+    } else {
+        ui.data_mut(|data| data.remove::<String>(id));
+    }
+}
+
+/// Read-only view of any `Display` value.
+pub fn display_ui<T: Display>(value: &T, ui: &mut Ui) {
+    ui.label(value.to_string());
+}
+
+/// Adapter that edits any `FromStr + Display` value with [`parse_ui_mut`],
+/// for types such as IP addresses or versions that have no `GuiInspect` impl.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Parsed<T>(pub T);
+
+impl<T> From<T> for Parsed<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> std::ops::Deref for Parsed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for Parsed<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> GuiInspect for Parsed<T>
+where
+    T: FromStr + Display,
+    T::Err: Display,
+{
+    fn ui(&self, ui: &mut Ui) {
+        display_ui(&self.0, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        parse_ui_mut(&mut self.0, ui);
+    }
+}
+
+// `DragValue` routes through `f64`, which cannot represent integers above 2^53 exactly,
+// so 64-bit and wider integers are dragged within that range and edited as text beyond it.
+impl_large_numerics!(u64 i64 u128 i128 usize isize);
 
 impl<T> GuiInspect for Vec<T>
 where
     T: GuiInspect + Default,
 {
     fn ui(&self, ui: &mut Ui) {
-        self.iter().for_each(|item| {
-            item.ui(ui);
+        vec_ui(self, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        vec_ui_mut(self, ui, LengthBounds::default(), T::default);
+    }
+}
+
+/// Limits on how far a sequence editor may grow or shrink.
+///
+/// The Add button is disabled once `max_len` items are present
+/// and the Remove button is disabled at `min_len` items.
+/// A `min_len` above `max_len` is lowered to it, so that a sequence
+/// outside the bounds can always be brought back within them.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LengthBounds {
+    pub min_len: Option<usize>,
+    pub max_len: Option<usize>,
+}
+
+impl LengthBounds {
+    pub fn can_add(&self, len: usize) -> bool {
+        self.max_len.is_none_or(|max_len| len < max_len)
+    }
+
+    pub fn can_remove(&self, len: usize) -> bool {
+        let min_len = match (self.min_len, self.max_len) {
+            (Some(min_len), Some(max_len)) => Some(min_len.min(max_len)),
+            (min_len, _) => min_len,
+        };
+        len > 0 && min_len.is_none_or(|min_len| len > min_len)
+    }
+}
+
+/// Read-only view of a slice, used by `Vec<T>` and by derived fields
+/// whose element type does not implement `Default`.
+pub fn vec_ui<T: GuiInspect>(items: &[T], ui: &mut Ui) {
+    list_ui(items, "Empty Vec", ui);
+}
+
+/// Read-only view shared by all sequence types.
+fn list_ui<'a, T: GuiInspect + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    empty_label: &str,
+    ui: &mut Ui,
+) {
+    let mut empty = true;
+    items.into_iter().for_each(|item| {
+        empty = false;
+        item.ui(ui);
+    });
+    if empty {
+        ui.label(empty_label);
+    }
+}
+
+/// Editable view of a `Vec<T>` that builds new elements with `new_item`
+/// and respects the given `bounds`.
+pub fn vec_ui_mut<T: GuiInspect>(
+    items: &mut Vec<T>,
+    ui: &mut Ui,
+    bounds: LengthBounds,
+    mut new_item: impl FnMut() -> T,
+) {
+    let (add, remove) = push_pop_ui_mut(items.len(), items.iter_mut(), ui, bounds);
+    if add {
+        items.push(new_item());
+    }
+    if remove {
+        items.pop();
+    }
+}
+
+/// Shared editor for vector-like sequences that grow and shrink at the back.
+/// Returns whether an element should be pushed and whether the last one should be popped.
+fn push_pop_ui_mut<'a, T: GuiInspect + 'a>(
+    len: usize,
+    items: impl IntoIterator<Item = &'a mut T>,
+    ui: &mut Ui,
+    bounds: LengthBounds,
+) -> (bool, bool) {
+    let mut add = false;
+    let mut remove = false;
+
+    sequence_ui(
+        ui,
+        |ui| {
+            add = ui
+                .add_enabled(bounds.can_add(len), egui::Button::new("Add"))
+                .clicked();
+            remove = ui
+                .add_enabled(bounds.can_remove(len), egui::Button::new("Remove Last"))
+                .clicked();
+        },
+        |ui| {
+            items.into_iter().for_each(|item| {
+                item.ui_mut(ui);
+            });
+        },
+    );
+
+    (add, remove)
+}
+
+/// Shared frame for sequence editors: a row of buttons above the elements.
+pub(crate) fn sequence_ui(ui: &mut Ui, buttons: impl FnOnce(&mut Ui), items: impl FnOnce(&mut Ui)) {
+    ui.group(|ui| {
+        ui.vertical(|ui| {
+            ui.horizontal(buttons);
+            ui.separator();
+            ui.vertical(items);
+        });
+    });
+}
+
+impl<T: GuiInspect> GuiInspect for [T] {
+    fn ui(&self, ui: &mut Ui) {
+        ui.vertical(|ui| {
+            self.iter().for_each(|item| {
+                item.ui(ui);
+            });
+        });
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        ui.vertical(|ui| {
+            self.iter_mut().for_each(|item| {
+                item.ui_mut(ui);
+            });
         });
-        if self.is_empty() {
-            ui.label("Empty Vec");
+    }
+}
+
+impl<T: GuiInspect> GuiInspect for &mut [T] {
+    fn ui(&self, ui: &mut Ui) {
+        (**self).ui(ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        (**self).ui_mut(ui);
+    }
+}
+
+/// Arrays of up to this many inline elements, such as numbers, are laid out
+/// in a single row, which suits positions, colors and other small vectors.
+const INLINE_ARRAY_LEN: usize = 4;
+
+impl<T: GuiInspect, const N: usize> GuiInspect for [T; N] {
+    fn ui(&self, ui: &mut Ui) {
+        if N <= INLINE_ARRAY_LEN && T::inline() {
+            ui.horizontal(|ui| {
+                self.iter().for_each(|item| {
+                    item.ui(ui);
+                });
+            });
+        } else {
+            self.as_slice().ui(ui);
         }
     }
 
     fn ui_mut(&mut self, ui: &mut Ui) {
-        ui.group(|ui| {
-            ui.vertical(|ui| {
-                ui.horizontal(|ui| {
-                    if ui.button("Add").clicked() {
-                        self.push(T::default());
-                    }
+        if N <= INLINE_ARRAY_LEN && T::inline() {
+            ui.horizontal(|ui| {
+                self.iter_mut().for_each(|item| {
+                    item.ui_mut(ui);
+                });
+            });
+        } else {
+            self.as_mut_slice().ui_mut(ui);
+        }
+    }
+}
 
-                    if ui
-                        .add_enabled(!self.is_empty(), egui::Button::new("Remove Last"))
-                        .clicked()
-                    {
-                        self.pop();
-                    }
+/// Read-only view of a nested array as an aligned grid of rows and columns.
+pub fn matrix_ui<T: GuiInspect, const R: usize, const C: usize>(matrix: &[[T; C]; R], ui: &mut Ui) {
+    egui::Grid::new(ui.next_auto_id())
+        .num_columns(C)
+        .show(ui, |ui| {
+            matrix.iter().for_each(|row| {
+                row.iter().for_each(|item| {
+                    item.ui(ui);
                 });
-                ui.separator();
-                ui.vertical(|ui| {
-                    self.iter_mut().for_each(|item| {
-                        item.ui_mut(ui);
-                    });
+                ui.end_row();
+            });
+        });
+}
+
+/// Editable view of a nested array as an aligned grid of rows and columns.
+pub fn matrix_ui_mut<T: GuiInspect, const R: usize, const C: usize>(
+    matrix: &mut [[T; C]; R],
+    ui: &mut Ui,
+) {
+    egui::Grid::new(ui.next_auto_id())
+        .num_columns(C)
+        .show(ui, |ui| {
+            matrix.iter_mut().for_each(|row| {
+                row.iter_mut().for_each(|item| {
+                    item.ui_mut(ui);
                 });
+                ui.end_row();
             });
         });
+}
+
+macro_rules! impl_gui_for_double_ended {
+    ($($t:ident => $empty_label:literal)*) => ($(
+        impl<T> GuiInspect for $t<T>
+        where
+            T: GuiInspect + Default,
+        {
+            fn ui(&self, ui: &mut Ui) {
+                list_ui(self, $empty_label, ui);
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                let is_empty = self.is_empty();
+                let mut action = None;
+
+                sequence_ui(
+                    ui,
+                    |ui| {
+                        if ui.button("Push Front").clicked() {
+                            action = Some(EndAction::PushFront);
+                        }
+                        if ui.button("Push Back").clicked() {
+                            action = Some(EndAction::PushBack);
+                        }
+                        if ui.add_enabled(!is_empty, egui::Button::new("Pop Front")).clicked() {
+                            action = Some(EndAction::PopFront);
+                        }
+                        if ui.add_enabled(!is_empty, egui::Button::new("Pop Back")).clicked() {
+                            action = Some(EndAction::PopBack);
+                        }
+                    },
+                    |ui| {
+                        self.iter_mut().for_each(|item| {
+                            item.ui_mut(ui);
+                        });
+                    },
+                );
+
+                match action {
+                    Some(EndAction::PushFront) => self.push_front(T::default()),
+                    Some(EndAction::PushBack) => self.push_back(T::default()),
+                    Some(EndAction::PopFront) => drop(self.pop_front()),
+                    Some(EndAction::PopBack) => drop(self.pop_back()),
+                    None => {}
+                }
+            }
+        }
+    )*)
+}
+
+enum EndAction {
+    PushFront,
+    PushBack,
+    PopFront,
+    PopBack,
+}
+
+impl_gui_for_double_ended!(VecDeque => "Empty VecDeque" LinkedList => "Empty LinkedList");
+
+/// A heap only exposes its greatest element mutably, so it is shown read-only,
+/// from greatest to least.
+impl<T: GuiInspect + Ord> GuiInspect for BinaryHeap<T> {
+    fn ui(&self, ui: &mut Ui) {
+        let mut items = self.iter().collect::<Vec<_>>();
+        items.sort_by(|left, right| right.cmp(left));
+        list_ui(items, "Empty BinaryHeap", ui);
     }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.ui(ui);
+    }
+}
+
+/// Shared editor for sets.
+///
+/// Elements can't be edited in place without breaking the set's ordering or hashing,
+/// so existing elements are read-only with a Remove button each, and `add_ui` draws
+/// the row above them that returns a new element once it should be added.
+/// Returns the element to add and the element to remove, if any were requested.
+fn set_ui_mut<'a, K: GuiInspect + Clone + 'a>(
+    items: impl IntoIterator<Item = &'a K>,
+    add_ui: impl FnOnce(&mut Ui) -> Option<K>,
+    ui: &mut Ui,
+) -> (Option<K>, Option<K>) {
+    let mut added = None;
+    let mut removed = None;
+
+    sequence_ui(
+        ui,
+        |ui| added = add_ui(ui),
+        |ui| {
+            let mut empty = true;
+            items.into_iter().for_each(|item| {
+                empty = false;
+                ui.horizontal(|ui| {
+                    if ui.small_button("Remove").clicked() {
+                        removed = Some(item.clone());
+                    }
+                    item.ui(ui);
+                });
+            });
+            if empty {
+                ui.label("Empty Set");
+            }
+        },
+    );
+
+    (added, removed)
+}
+
+/// Composes a new set element in a pending editor kept between frames,
+/// returning it once the Add button is clicked.
+fn pending_item_ui<K>(contains: impl Fn(&K) -> bool, ui: &mut Ui) -> Option<K>
+where
+    K: GuiInspect + Clone + Default + 'static,
+{
+    let pending_id = ui.next_auto_id().with("pending");
+    let mut pending = take_stashed::<K>(pending_id).unwrap_or_default();
+    pending.ui_mut(ui);
+    let added = ui
+        .add_enabled(!contains(&pending), egui::Button::new("Add"))
+        .clicked()
+        .then(|| pending.clone());
+    stash(pending_id, pending);
+    added
+}
+
+/// Hash sets have no inherent order, so elements are sorted by their `Debug`
+/// representation to keep the list from shuffling between frames.
+fn sorted_by_debug<K: std::fmt::Debug>(set: &HashSet<K>) -> Vec<&K> {
+    let mut items = set.iter().collect::<Vec<_>>();
+    items.sort_by_cached_key(|item| format!("{item:?}"));
+    items
 }
 
-impl<K> GuiInspect for std::collections::HashSet<K>
+/// The plain editor can't keep an element between frames, so it only adds `K::default()`.
+/// Derived fields of type `HashSet<K>` use [`hash_set_ui_mut`] instead.
+impl<K> GuiInspect for HashSet<K>
 where
     K: GuiInspect + Clone + std::hash::Hash + Eq + Default + std::fmt::Debug,
 {
-    fn ui(&self, _ui: &mut Ui) {}
-    fn ui_mut(&mut self, _ui: &mut Ui) {}
+    fn ui(&self, ui: &mut Ui) {
+        list_ui(sorted_by_debug(self), "Empty Set", ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let add_ui = |ui: &mut Ui| {
+            let item = K::default();
+            ui.add_enabled(!self.contains(&item), egui::Button::new("Add Default"))
+                .clicked()
+                .then_some(item)
+        };
+        let (added, removed) = set_ui_mut(sorted_by_debug(self), add_ui, ui);
+        if let Some(item) = added {
+            self.insert(item);
+        }
+        if let Some(item) = removed {
+            self.remove(&item);
+        }
+    }
+}
+
+/// Editable view of a `HashSet<K>`, generated for derived fields of that type.
+///
+/// New elements are composed in a pending editor before being added,
+/// which needs `K: 'static` to keep the pending element between frames.
+pub fn hash_set_ui_mut<K>(set: &mut HashSet<K>, ui: &mut Ui)
+where
+    K: GuiInspect + Clone + std::hash::Hash + Eq + Default + std::fmt::Debug + 'static,
+{
+    let add_ui = |ui: &mut Ui| pending_item_ui(|item| set.contains(item), ui);
+    let (added, removed) = set_ui_mut(sorted_by_debug(set), add_ui, ui);
+    if let Some(item) = added {
+        set.insert(item);
+    }
+    if let Some(item) = removed {
+        set.remove(&item);
+    }
+}
+
+impl<K> GuiInspect for BTreeSet<K>
+where
+    K: GuiInspect + Clone + Ord + Default + 'static,
+{
+    fn ui(&self, ui: &mut Ui) {
+        list_ui(self, "Empty Set", ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let add_ui = |ui: &mut Ui| pending_item_ui(|item| self.contains(item), ui);
+        let (added, removed) = set_ui_mut(self.iter(), add_ui, ui);
+        if let Some(item) = added {
+            self.insert(item);
+        }
+        if let Some(item) = removed {
+            self.remove(&item);
+        }
+    }
 }
 
 impl<K, V> GuiInspect for std::collections::BTreeMap<K, V>
@@ -164,6 +1076,389 @@ where
     fn ui_mut(&mut self, _ui: &mut Ui) {}
 }
 
+#[cfg(feature = "indexmap")]
+impl<K, V, S> GuiInspect for indexmap::IndexMap<K, V, S>
+where
+    K: GuiInspect + Clone + std::hash::Hash + Eq + Default + Send + Sync + 'static,
+    V: GuiInspect + Default,
+    S: std::hash::BuildHasher,
+{
+    fn ui(&self, ui: &mut Ui) {
+        ui.vertical(|ui| {
+            self.iter().for_each(|(key, value)| {
+                ui.horizontal(|ui| {
+                    key.ui(ui);
+                    value.ui(ui);
+                });
+            });
+            if self.is_empty() {
+                ui.label("Empty IndexMap");
+            }
+        });
+    }
+
+    /// Keys are read-only because replacing one would move its entry to the back.
+    /// New keys are composed in a pending editor and appended, preserving insertion order.
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let pending_id = ui.next_auto_id().with("pending");
+        let mut pending = ui
+            .data_mut(|data| data.remove_temp::<K>(pending_id))
+            .unwrap_or_default();
+        let contains_pending = self.contains_key(&pending);
+        let mut add = false;
+        let mut removed = None;
+
+        sequence_ui(
+            ui,
+            |ui| {
+                pending.ui_mut(ui);
+                add = ui
+                    .add_enabled(!contains_pending, egui::Button::new("Add"))
+                    .clicked();
+            },
+            |ui| {
+                self.iter_mut()
+                    .enumerate()
+                    .for_each(|(index, (key, value))| {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("Remove").clicked() {
+                                removed = Some(index);
+                            }
+                            key.ui(ui);
+                            value.ui_mut(ui);
+                        });
+                    });
+                if self.is_empty() {
+                    ui.label("Empty IndexMap");
+                }
+            },
+        );
+
+        if let Some(index) = removed {
+            self.shift_remove_index(index);
+        }
+        if add && !self.contains_key(&pending) {
+            self.insert(pending.clone(), V::default());
+        }
+        ui.data_mut(|data| data.insert_temp(pending_id, pending));
+    }
+}
+
+#[cfg(feature = "smallvec")]
+impl<A> GuiInspect for smallvec::SmallVec<A>
+where
+    A: smallvec::Array,
+    A::Item: GuiInspect + Default,
+{
+    fn ui(&self, ui: &mut Ui) {
+        list_ui(self.iter(), "Empty SmallVec", ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let (add, remove) =
+            push_pop_ui_mut(self.len(), self.iter_mut(), ui, LengthBounds::default());
+        if add {
+            self.push(A::Item::default());
+        }
+        if remove {
+            self.pop();
+        }
+    }
+}
+
+/// Implements `GuiInspect` for fixed-capacity vectors,
+/// disabling the Add button once the capacity is reached.
+#[cfg(any(feature = "arrayvec", feature = "heapless"))]
+macro_rules! impl_gui_for_fixed_capacity {
+    ($($t:ty => $empty_label:literal, $try_push:ident)*) => ($(
+        impl<T, const N: usize> GuiInspect for $t
+        where
+            T: GuiInspect + Default,
+        {
+            fn ui(&self, ui: &mut Ui) {
+                list_ui(self.iter(), $empty_label, ui);
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                let bounds = LengthBounds {
+                    min_len: None,
+                    max_len: Some(N),
+                };
+                let (add, remove) = push_pop_ui_mut(self.len(), self.iter_mut(), ui, bounds);
+                if add {
+                    // The bounds keep the vector below capacity, so this can't fail.
+                    let _ = self.$try_push(T::default());
+                }
+                if remove {
+                    self.pop();
+                }
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "arrayvec")]
+impl_gui_for_fixed_capacity!(arrayvec::ArrayVec<T, N> => "Empty ArrayVec", try_push);
+
+#[cfg(feature = "heapless")]
+impl_gui_for_fixed_capacity!(heapless::Vec<T, N> => "Empty heapless::Vec", push);
+
+macro_rules! impl_gui_for_parsed {
+    ($($t:ty)*) => ($(
+        impl GuiInspect for $t {
+            fn ui(&self, ui: &mut Ui) {
+                display_ui(self, ui);
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                parse_ui_mut(self, ui);
+            }
+        }
+    )*)
+}
+
+impl_gui_for_parsed!(IpAddr Ipv4Addr Ipv6Addr SocketAddr SocketAddrV4 SocketAddrV6);
+
+// The wide non-zero integers share the text editor of their primitives,
+// whose `FromStr` impls already reject zero.
+impl_gui_for_parsed!(
+    NonZero<u64> NonZero<i64> NonZero<u128> NonZero<i128> NonZero<usize> NonZero<isize>
+);
+
+macro_rules! impl_gui_for_non_zero {
+    (signed: $($t:ty)*) => ($(
+        impl GuiInspect for NonZero<$t> {
+            fn ui(&self, ui: &mut Ui) {
+                ui.label(format!("{self}"));
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                let previous = self.get();
+                let mut value = previous;
+                ui.add(DragValue::new(&mut value))
+                    .on_hover_text("Must be non-zero");
+                // Zero is stepped over in the direction of the drag, so that values can cross it.
+                if value == 0 {
+                    value = if previous > 0 { -1 } else { 1 };
+                }
+                *self = NonZero::new(value).unwrap_or(*self);
+            }
+
+            fn inline() -> bool {
+                true
+            }
+        }
+    )*);
+    (unsigned: $($t:ty)*) => ($(
+        impl GuiInspect for NonZero<$t> {
+            fn ui(&self, ui: &mut Ui) {
+                ui.label(format!("{self}"));
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                let mut value = self.get();
+                ui.add(DragValue::new(&mut value).range(1..=<$t>::MAX));
+                *self = NonZero::new(value).unwrap_or(*self);
+            }
+
+            fn inline() -> bool {
+                true
+            }
+        }
+    )*);
+}
+
+impl_gui_for_non_zero!(signed: i8 i16 i32);
+impl_gui_for_non_zero!(unsigned: u8 u16 u32);
+
+impl<T: GuiInspect> GuiInspect for Wrapping<T> {
+    fn ui(&self, ui: &mut Ui) {
+        self.0.ui(ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        self.0.ui_mut(ui);
+    }
+
+    fn inline() -> bool {
+        T::inline()
+    }
+}
+
+impl<T: ?Sized> GuiInspect for PhantomData<T> {
+    fn ui(&self, _ui: &mut Ui) {}
+    fn ui_mut(&mut self, _ui: &mut Ui) {}
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum DurationUnit {
+    Nanoseconds,
+    Microseconds,
+    Milliseconds,
+    #[default]
+    Seconds,
+    Minutes,
+    Hours,
+}
+
+impl DurationUnit {
+    const ALL: [Self; 6] = [
+        Self::Nanoseconds,
+        Self::Microseconds,
+        Self::Milliseconds,
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hours,
+    ];
+
+    fn seconds(self) -> f64 {
+        match self {
+            Self::Nanoseconds => 1e-9,
+            Self::Microseconds => 1e-6,
+            Self::Milliseconds => 1e-3,
+            Self::Seconds => 1.0,
+            Self::Minutes => 60.0,
+            Self::Hours => 3600.0,
+        }
+    }
+
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Nanoseconds => "ns",
+            Self::Microseconds => "µs",
+            Self::Milliseconds => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+        }
+    }
+}
+
+impl GuiInspect for Duration {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(format!("{self:?}"));
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let unit_id = ui.next_auto_id().with("unit");
+        let mut unit = ui
+            .data(|data| data.get_temp::<DurationUnit>(unit_id))
+            .unwrap_or_default();
+
+        ui.horizontal(|ui| {
+            let mut value = self.as_secs_f64() / unit.seconds();
+            if ui
+                .add(DragValue::new(&mut value).range(0.0..=f64::MAX))
+                .changed()
+            {
+                *self = Duration::try_from_secs_f64(value * unit.seconds()).unwrap_or(*self);
+            }
+
+            egui::ComboBox::from_id_salt(unit_id)
+                .selected_text(unit.suffix())
+                .width(48.0)
+                .show_ui(ui, |ui| {
+                    DurationUnit::ALL.iter().for_each(|option| {
+                        ui.selectable_value(&mut unit, *option, option.suffix());
+                    });
+                });
+        });
+
+        ui.data_mut(|data| data.insert_temp(unit_id, unit));
+    }
+}
+
+impl GuiInspect for PathBuf {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(self.display().to_string());
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        // Paths that aren't valid UTF-8 can't round-trip through a text box.
+        let Some(text) = self.to_str() else {
+            return self.ui(ui);
+        };
+        let mut text = text.to_string();
+        if ui.text_edit_singleline(&mut text).changed() {
+            *self = PathBuf::from(text);
+        }
+    }
+}
+
+impl GuiInspect for OsString {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(self.to_string_lossy());
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let Some(text) = self.to_str() else {
+            return self.ui(ui);
+        };
+        let mut text = text.to_string();
+        if ui.text_edit_singleline(&mut text).changed() {
+            *self = OsString::from(text);
+        }
+    }
+}
+
+/// Edits a pair of bounds, pulling the other bound along
+/// whenever an edit would leave `start` greater than `end`.
+fn bounds_ui_mut<T>(start: &mut T, end: &mut T, separator: &str, ui: &mut Ui)
+where
+    T: GuiInspect + PartialOrd + Clone,
+{
+    ui.horizontal(|ui| {
+        let previous_start = start.clone();
+        start.ui_mut(ui);
+        ui.label(separator);
+        end.ui_mut(ui);
+
+        if *start > *end {
+            if *start != previous_start {
+                *end = start.clone();
+            } else {
+                *start = end.clone();
+            }
+        }
+    });
+}
+
+impl<T> GuiInspect for Range<T>
+where
+    T: GuiInspect + PartialOrd + Clone,
+{
+    fn ui(&self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            self.start.ui(ui);
+            ui.label("..");
+            self.end.ui(ui);
+        });
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        bounds_ui_mut(&mut self.start, &mut self.end, "..", ui);
+    }
+}
+
+impl<T> GuiInspect for RangeInclusive<T>
+where
+    T: GuiInspect + PartialOrd + Clone,
+{
+    fn ui(&self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            self.start().ui(ui);
+            ui.label("..=");
+            self.end().ui(ui);
+        });
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        let (mut start, mut end) = self.clone().into_inner();
+        bounds_ui_mut(&mut start, &mut end, "..=", ui);
+        *self = start..=end;
+    }
+}
+
 macro_rules! impl_gui_for_tuples {
     ( $( $name:ident )+ ) => {
         impl<$($name: GuiInspect),+> GuiInspect for ($($name,)+) {
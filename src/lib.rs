@@ -40,10 +40,12 @@
 //!         metadata: Metadata,
 //!     },
 //!
+//!     // Can't be picked, and only listed in the picker while active
 //!     #[enum2egui(skip)]
 //!     SkippedGreen,
 //!
-//!     #[enum2egui(skip)]
+//!     // Left out of the picker entirely
+//!     #[enum2egui(hidden)]
 //!     #[enum2str("Skipped Custom")]
 //!     SkippedCustom(u8, u8, u8),
 //!
@@ -107,8 +109,69 @@
 //! ```
 //!
 //! ![image](https://github.com/matthewjberger/enum2egui/assets/7131091/4a7119e0-0ea1-4ce6-b492-8eca48da792c)
+//!
+//! ## Attributes
+//!
+//! Attributes are written as `#[enum2egui(...)]` on the deriving type, its variants or its fields.
+//! Unknown keys, values of the wrong type and keys written where they don't apply are compile errors.
+//!
+//! | Attribute | Applies to | Effect |
+//! | --- | --- | --- |
+//! | `label = "..."` | structs, variants, fields | Text shown in place of the name. |
+//! | `remote = "path::Type"` | structs, enums | Derives for a foreign type through a mirror declaration, generating `Mirror::ui` and `Mirror::ui_mut`. |
+//! | `picker = "combo"` | enums, fields | Variant selector: `combo` (default), `radio`, `tabs` or `search`. On a field, it applies to every enum directly in the field, such as each element of a `Vec`. |
+//! | `show_discriminant` | enums without fields | Shows each variant's discriminant and lets it be typed in. |
+//! | `skip` | variants, fields | A skipped field isn't shown. A skipped variant can't be picked, and while active it is listed in the picker, disabled, with its fields hidden. |
+//! | `hidden` | variants | The variant is left out of the picker entirely, and nothing is shown for its fields while active. |
+//! | `group = "..."` | variants | Lists the variant under a heading in the picker. |
+//! | `tooltip = "..."` | variants | Hover text in the picker. Defaults to the variant's doc comment. |
+//! | `with = "path"` | fields | Renders the field with `path::ui` and `path::ui_mut`, usually a remote derive's mirror. |
+//! | `flags` | `Vec<E>` and `HashSet<E>` fields | A checkbox per variant of a derived enum without fields. |
+//! | `matrix` | nested array fields | An aligned grid of rows and columns. |
+//! | `parse` | `FromStr + Display` fields | A text box that accepts anything that parses. |
+//! | `color`, `alpha`, `hsva` | `[u8; 3]`, `[u8; 4]`, `[f32; 3]`, `[f32; 4]`, `(u8, u8, u8)`, `(u8, u8, u8, u8)` and `u32` fields | A color picker, with `alpha` for an alpha channel and `hsva` for HSVA components. |
+//! | `option_style = "checkbox"` | `Option` fields | `checkbox` (default) or `button`. A cleared value is restored when set again. |
+//! | `min_len`, `max_len`, `new_item = "path"` | `Vec` fields | Length bounds, and the function that creates added items instead of `Default::default`. |
+//! | `multiline`, `rows`, `password`, `max_chars`, `hint`, `code`, `language` | `String` fields | Text box options. |
+//!
+//! ### Upgrading: `skip` on variants
+//!
+//! Skipped variants used to be left out of the picker. While one is active, it is now listed
+//! as a disabled entry, so that the picker shows which variant is active.
+//! Its fields still aren't shown, so they don't need to implement `GuiInspect`.
+//! Use `#[enum2egui(hidden)]` to never list a variant.
+//!
+//! ## Features
+//!
+//! | Feature | Adds `GuiInspect` for |
+//! | --- | --- |
+//! | `arrayvec`, `heapless`, `smallvec` | Their vector types, within the fixed capacity where there is one. |
+//! | `bitflags` | `bitflags` types through `impl_gui_for_bitflags!`. |
+//! | `chrono`, `time` | Dates, times and date-times with a calendar popup. |
+//! | `enumset` | `EnumSet`, with a checkbox per variant. |
+//! | `glam`, `mint`, `nalgebra` | Vectors, matrices, quaternions and transforms. |
+//! | `hashbrown`, `indexmap` | Their maps and sets. |
+//! | `ron`, `serde_json`, `toml` | Their dynamic `Value` trees. |
+//! | `serde` | `SerdeInspect<T>` and `serde_ui_mut`, which edit any serde type as a value tree. |
+#[macro_use]
+mod macros;
+
+mod color;
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod datetime;
+mod flags;
 mod gui;
+#[cfg(any(feature = "glam", feature = "mint", feature = "nalgebra"))]
+mod math;
+mod picker;
+#[cfg(feature = "serde")]
+mod serde_inspect;
+mod style;
+#[cfg(any(feature = "ron", feature = "serde_json", feature = "toml"))]
+mod value;
 
-pub use self::gui::*;
+#[cfg(feature = "serde")]
+pub use self::serde_inspect::*;
+pub use self::{color::*, flags::*, gui::*, picker::*, style::*};
 pub use egui;
 pub use enum2egui_derive::Gui;
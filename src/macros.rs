@@ -0,0 +1,32 @@
+/// Implements `GuiInspect` for small structs such as vectors by laying out
+/// each of their fields in a single row. A generic type names its parameter
+/// and bound in place, as in `Vector2<T: GuiInspect> => { x, y }`.
+macro_rules! impl_gui_for_inline {
+    (@impl [$($generics:tt)*] $t:ty => { $($field:ident),* }) => {
+        impl<$($generics)*> $crate::GuiInspect for $t {
+            fn ui(&self, ui: &mut $crate::egui::Ui) {
+                ui.horizontal(|ui| {
+                    $(
+                        ui.label(stringify!($field));
+                        $crate::GuiInspect::ui(&self.$field, ui);
+                    )*
+                });
+            }
+
+            fn ui_mut(&mut self, ui: &mut $crate::egui::Ui) {
+                ui.horizontal(|ui| {
+                    $(
+                        ui.label(stringify!($field));
+                        $crate::GuiInspect::ui_mut(&mut self.$field, ui);
+                    )*
+                });
+            }
+        }
+    };
+    ($($t:ident<$generic:ident: $bound:path> => { $($field:ident),* })*) => ($(
+        impl_gui_for_inline!(@impl [$generic: $bound] $t<$generic> => { $($field),* });
+    )*);
+    ($($t:ty => { $($field:ident),* })*) => ($(
+        impl_gui_for_inline!(@impl [] $t => { $($field),* });
+    )*);
+}
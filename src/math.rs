@@ -0,0 +1,509 @@
+use egui::{DragValue, Ui};
+
+const AXES: [&str; 3] = ["x", "y", "z"];
+
+/// Read-only view of a rotation given as Euler angles in radians, shown in degrees.
+fn euler_ui(radians: [f64; 3], ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        AXES.iter().zip(radians).for_each(|(axis, angle)| {
+            ui.label(*axis);
+            ui.label(format!("{:.1}°", angle.to_degrees()));
+        });
+    });
+}
+
+/// Edits a rotation given as Euler angles in radians, shown in degrees.
+/// Returns the new angles in radians if any of them changed.
+fn euler_ui_mut(radians: [f64; 3], ui: &mut Ui) -> Option<[f64; 3]> {
+    let mut degrees = radians.map(f64::to_degrees);
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        AXES.iter()
+            .zip(degrees.iter_mut())
+            .for_each(|(axis, angle)| {
+                ui.label(*axis);
+                changed |= ui
+                    .add(DragValue::new(angle).suffix("°").speed(1.0))
+                    .changed();
+            });
+    });
+    changed.then(|| degrees.map(f64::to_radians))
+}
+
+#[cfg(any(feature = "glam", feature = "mint"))]
+fn transpose<T: Copy, const R: usize, const C: usize>(matrix: [[T; C]; R]) -> [[T; R]; C] {
+    std::array::from_fn(|column| std::array::from_fn(|row| matrix[row][column]))
+}
+
+/// Float types whose angles can be shown through the shared `f64` Euler editor.
+#[cfg(any(feature = "mint", feature = "nalgebra"))]
+pub trait Float: Copy {
+    fn widen(self) -> f64;
+    fn narrow(value: f64) -> Self;
+}
+
+#[cfg(any(feature = "mint", feature = "nalgebra"))]
+impl Float for f32 {
+    fn widen(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn narrow(value: f64) -> Self {
+        value as f32
+    }
+}
+
+#[cfg(any(feature = "mint", feature = "nalgebra"))]
+impl Float for f64 {
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn narrow(value: f64) -> Self {
+        value
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_impls {
+    use super::{euler_ui, euler_ui_mut, transpose};
+    use crate::{GuiInspect, matrix_ui, matrix_ui_mut};
+    use egui::Ui;
+    use glam::{
+        Affine2, Affine3A, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4,
+        EulerRot, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2,
+        Vec3, Vec3A, Vec4,
+    };
+
+    impl_gui_for_inline!(
+        Vec2 => { x, y }
+        Vec3 => { x, y, z }
+        Vec3A => { x, y, z }
+        Vec4 => { x, y, z, w }
+        DVec2 => { x, y }
+        DVec3 => { x, y, z }
+        DVec4 => { x, y, z, w }
+        IVec2 => { x, y }
+        IVec3 => { x, y, z }
+        IVec4 => { x, y, z, w }
+        UVec2 => { x, y }
+        UVec3 => { x, y, z }
+        UVec4 => { x, y, z, w }
+    );
+
+    // glam stores matrices by column, so they are transposed to be shown row by row.
+    macro_rules! impl_gui_for_matrix {
+        ($($t:ty)*) => ($(
+            impl GuiInspect for $t {
+                fn ui(&self, ui: &mut Ui) {
+                    matrix_ui(&transpose(self.to_cols_array_2d()), ui);
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    let mut rows = transpose(self.to_cols_array_2d());
+                    matrix_ui_mut(&mut rows, ui);
+                    *self = <$t>::from_cols_array_2d(&transpose(rows));
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_matrix!(Mat2 Mat3 Mat3A Mat4 DMat2 DMat3 DMat4);
+
+    impl GuiInspect for Quat {
+        fn ui(&self, ui: &mut Ui) {
+            self.as_dquat().ui(ui);
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let mut rotation = self.as_dquat();
+            rotation.ui_mut(ui);
+            if rotation != self.as_dquat() {
+                *self = rotation.as_quat();
+            }
+        }
+    }
+
+    impl GuiInspect for DQuat {
+        fn ui(&self, ui: &mut Ui) {
+            let (x, y, z) = self.to_euler(EulerRot::XYZ);
+            euler_ui([x, y, z], ui);
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let (x, y, z) = self.to_euler(EulerRot::XYZ);
+            if let Some([x, y, z]) = euler_ui_mut([x, y, z], ui) {
+                *self = DQuat::from_euler(EulerRot::XYZ, x, y, z).normalize();
+            }
+        }
+    }
+
+    macro_rules! impl_gui_for_affine_2d {
+        ($($t:ty)*) => ($(
+            impl GuiInspect for $t {
+                fn ui(&self, ui: &mut Ui) {
+                    let (scale, angle, translation) = self.to_scale_angle_translation();
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            translation.ui(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("angle");
+                            ui.label(format!("{:.1}°", angle.to_degrees()));
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("scale");
+                            scale.ui(ui);
+                        });
+                    });
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    let original = self.to_scale_angle_translation();
+                    let (mut scale, mut angle, mut translation) = original;
+                    let mut degrees = angle.to_degrees();
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            translation.ui_mut(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("angle");
+                            if ui.add(egui::DragValue::new(&mut degrees).suffix("°")).changed() {
+                                angle = degrees.to_radians();
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("scale");
+                            scale.ui_mut(ui);
+                        });
+                    });
+                    if (scale, angle, translation) != original {
+                        *self = <$t>::from_scale_angle_translation(scale, angle, translation);
+                    }
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_affine_2d!(Affine2 DAffine2);
+
+    macro_rules! impl_gui_for_affine_3d {
+        ($($t:ty)*) => ($(
+            impl GuiInspect for $t {
+                fn ui(&self, ui: &mut Ui) {
+                    let (scale, rotation, translation) = self.to_scale_rotation_translation();
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            translation.ui(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("rotation");
+                            rotation.ui(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("scale");
+                            scale.ui(ui);
+                        });
+                    });
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    let original = self.to_scale_rotation_translation();
+                    let (mut scale, mut rotation, mut translation) = original;
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            translation.ui_mut(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("rotation");
+                            rotation.ui_mut(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("scale");
+                            scale.ui_mut(ui);
+                        });
+                    });
+                    if (scale, rotation, translation) != original {
+                        *self = <$t>::from_scale_rotation_translation(scale, rotation, translation);
+                    }
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_affine_3d!(Affine3A DAffine3);
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impls {
+    use super::{Float, euler_ui, euler_ui_mut};
+    use crate::GuiInspect;
+    use egui::Ui;
+    use nalgebra::{
+        Isometry2, Isometry3, Point, RealField, SMatrix, Scalar, UnitComplex, UnitQuaternion,
+    };
+
+    /// Column vectors are laid out in a single row, other matrices as a grid.
+    impl<T: GuiInspect + Scalar, const R: usize, const C: usize> GuiInspect for SMatrix<T, R, C> {
+        fn ui(&self, ui: &mut Ui) {
+            if C == 1 {
+                ui.horizontal(|ui| {
+                    self.iter().for_each(|value| {
+                        value.ui(ui);
+                    });
+                });
+                return;
+            }
+
+            egui::Grid::new(ui.next_auto_id())
+                .num_columns(C)
+                .show(ui, |ui| {
+                    (0..R).for_each(|row| {
+                        (0..C).for_each(|column| {
+                            self[(row, column)].ui(ui);
+                        });
+                        ui.end_row();
+                    });
+                });
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            if C == 1 {
+                ui.horizontal(|ui| {
+                    self.iter_mut().for_each(|value| {
+                        value.ui_mut(ui);
+                    });
+                });
+                return;
+            }
+
+            egui::Grid::new(ui.next_auto_id())
+                .num_columns(C)
+                .show(ui, |ui| {
+                    (0..R).for_each(|row| {
+                        (0..C).for_each(|column| {
+                            self[(row, column)].ui_mut(ui);
+                        });
+                        ui.end_row();
+                    });
+                });
+        }
+    }
+
+    impl<T: GuiInspect + Scalar, const D: usize> GuiInspect for Point<T, D> {
+        fn ui(&self, ui: &mut Ui) {
+            self.coords.ui(ui);
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            self.coords.ui_mut(ui);
+        }
+    }
+
+    impl<T> GuiInspect for UnitQuaternion<T>
+    where
+        T: RealField + Float,
+    {
+        fn ui(&self, ui: &mut Ui) {
+            let (roll, pitch, yaw) = self.euler_angles();
+            euler_ui([roll, pitch, yaw].map(T::widen), ui);
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let (roll, pitch, yaw) = self.euler_angles();
+            let radians = [roll, pitch, yaw].map(T::widen);
+            if let Some(radians) = euler_ui_mut(radians, ui) {
+                let [roll, pitch, yaw] = radians.map(T::narrow);
+                *self = UnitQuaternion::from_euler_angles(roll, pitch, yaw);
+            }
+        }
+    }
+
+    impl<T> GuiInspect for UnitComplex<T>
+    where
+        T: RealField + Float,
+    {
+        fn ui(&self, ui: &mut Ui) {
+            let angle = self.angle().widen();
+            ui.label(format!("{:.1}°", angle.to_degrees()));
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let angle = self.angle().widen();
+            let mut degrees = angle.to_degrees();
+            if ui
+                .add(egui::DragValue::new(&mut degrees).suffix("°"))
+                .changed()
+            {
+                *self = UnitComplex::new(T::narrow(degrees.to_radians()));
+            }
+        }
+    }
+
+    macro_rules! impl_gui_for_isometry {
+        ($($t:ident)*) => ($(
+            impl<T> GuiInspect for $t<T>
+            where
+                T: GuiInspect + RealField + Float,
+            {
+                fn ui(&self, ui: &mut Ui) {
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            self.translation.vector.ui(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("rotation");
+                            self.rotation.ui(ui);
+                        });
+                    });
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            ui.label("translation");
+                            self.translation.vector.ui_mut(ui);
+                        });
+                        ui.horizontal(|ui| {
+                            ui.label("rotation");
+                            self.rotation.ui_mut(ui);
+                        });
+                    });
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_isometry!(Isometry2 Isometry3);
+}
+
+#[cfg(feature = "mint")]
+mod mint_impls {
+    use super::{Float, euler_ui, euler_ui_mut, transpose};
+    use crate::{GuiInspect, matrix_ui, matrix_ui_mut};
+    use egui::Ui;
+    use mint::{
+        ColumnMatrix2, ColumnMatrix2x3, ColumnMatrix2x4, ColumnMatrix3, ColumnMatrix3x2,
+        ColumnMatrix3x4, ColumnMatrix4, ColumnMatrix4x2, ColumnMatrix4x3, Point2, Point3,
+        Quaternion, RowMatrix2, RowMatrix2x3, RowMatrix2x4, RowMatrix3, RowMatrix3x2, RowMatrix3x4,
+        RowMatrix4, RowMatrix4x2, RowMatrix4x3, Vector2, Vector3, Vector4,
+    };
+
+    impl_gui_for_inline!(
+        Vector2<T: GuiInspect> => { x, y }
+        Vector3<T: GuiInspect> => { x, y, z }
+        Vector4<T: GuiInspect> => { x, y, z, w }
+        Point2<T: GuiInspect> => { x, y }
+        Point3<T: GuiInspect> => { x, y, z }
+    );
+
+    macro_rules! impl_gui_for_row_matrix {
+        ($($t:ident => ($rows:literal, $columns:literal))*) => ($(
+            impl<T: GuiInspect + Copy> GuiInspect for $t<T> {
+                fn ui(&self, ui: &mut Ui) {
+                    let rows: [[T; $columns]; $rows] = (*self).into();
+                    matrix_ui(&rows, ui);
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    let mut rows: [[T; $columns]; $rows] = (*self).into();
+                    matrix_ui_mut(&mut rows, ui);
+                    *self = rows.into();
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_row_matrix!(
+        RowMatrix2 => (2, 2)
+        RowMatrix2x3 => (2, 3)
+        RowMatrix2x4 => (2, 4)
+        RowMatrix3x2 => (3, 2)
+        RowMatrix3 => (3, 3)
+        RowMatrix3x4 => (3, 4)
+        RowMatrix4x2 => (4, 2)
+        RowMatrix4x3 => (4, 3)
+        RowMatrix4 => (4, 4)
+    );
+
+    // Column matrices convert to arrays of columns, so they are transposed to be shown row by row.
+    macro_rules! impl_gui_for_column_matrix {
+        ($($t:ident => ($rows:literal, $columns:literal))*) => ($(
+            impl<T: GuiInspect + Copy> GuiInspect for $t<T> {
+                fn ui(&self, ui: &mut Ui) {
+                    let columns: [[T; $rows]; $columns] = (*self).into();
+                    matrix_ui(&transpose(columns), ui);
+                }
+
+                fn ui_mut(&mut self, ui: &mut Ui) {
+                    let columns: [[T; $rows]; $columns] = (*self).into();
+                    let mut rows = transpose(columns);
+                    matrix_ui_mut(&mut rows, ui);
+                    *self = transpose(rows).into();
+                }
+            }
+        )*)
+    }
+
+    impl_gui_for_column_matrix!(
+        ColumnMatrix2 => (2, 2)
+        ColumnMatrix2x3 => (2, 3)
+        ColumnMatrix2x4 => (2, 4)
+        ColumnMatrix3x2 => (3, 2)
+        ColumnMatrix3 => (3, 3)
+        ColumnMatrix3x4 => (3, 4)
+        ColumnMatrix4x2 => (4, 2)
+        ColumnMatrix4x3 => (4, 3)
+        ColumnMatrix4 => (4, 4)
+    );
+
+    /// mint has no math of its own, so quaternions are converted to and from
+    /// roll (x), pitch (y) and yaw (z) angles here.
+    fn to_euler([x, y, z, w]: [f64; 4]) -> [f64; 3] {
+        let roll = (2.0 * (w * x + y * z)).atan2(1.0 - 2.0 * (x * x + y * y));
+        let pitch = (2.0 * (w * y - z * x)).clamp(-1.0, 1.0).asin();
+        let yaw = (2.0 * (w * z + x * y)).atan2(1.0 - 2.0 * (y * y + z * z));
+        [roll, pitch, yaw]
+    }
+
+    fn from_euler([roll, pitch, yaw]: [f64; 3]) -> [f64; 4] {
+        let (sr, cr) = (roll / 2.0).sin_cos();
+        let (sp, cp) = (pitch / 2.0).sin_cos();
+        let (sy, cy) = (yaw / 2.0).sin_cos();
+        let quaternion = [
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy,
+            cr * cp * cy + sr * sp * sy,
+        ];
+        let length = quaternion
+            .iter()
+            .map(|value| value * value)
+            .sum::<f64>()
+            .sqrt();
+        quaternion.map(|value| value / length)
+    }
+
+    impl<T: Float> GuiInspect for Quaternion<T> {
+        fn ui(&self, ui: &mut Ui) {
+            let quaternion = [self.v.x, self.v.y, self.v.z, self.s].map(T::widen);
+            euler_ui(to_euler(quaternion), ui);
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            let quaternion = [self.v.x, self.v.y, self.v.z, self.s].map(T::widen);
+            if let Some(radians) = euler_ui_mut(to_euler(quaternion), ui) {
+                let [x, y, z, s] = from_euler(radians).map(T::narrow);
+                *self = Quaternion {
+                    v: Vector3 { x, y, z },
+                    s,
+                };
+            }
+        }
+    }
+}
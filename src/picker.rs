@@ -0,0 +1,336 @@
+use crate::parse_with_ui_mut;
+use egui::Ui;
+
+/// How a derived enum presents its variants, set with
+/// `#[enum2egui(picker = "...")]` on the enum or on a field holding it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Picker {
+    /// A drop-down list, the default.
+    #[default]
+    Combo,
+    /// A row of radio buttons, quickest for enums with few variants.
+    Radio,
+    /// A row of selectable labels, like a tab bar.
+    Tabs,
+    /// A drop-down list with a filter text box, for enums with many variants.
+    Search,
+}
+
+fn picker_id() -> egui::Id {
+    egui::Id::new("enum2egui_picker")
+}
+
+/// Renders `add_contents` with `picker` overriding the picker of the derived enums inside it.
+/// Generated for fields marked `#[enum2egui(picker = "...")]`.
+///
+/// The override applies to every enum directly in the field, such as each element of a
+/// `Vec<Mode>`, but not to enums nested inside derived types, as [`crate::nested_ui`]
+/// clears it for its contents.
+pub fn with_picker(ui: &mut Ui, picker: Picker, add_contents: impl FnOnce(&mut Ui)) {
+    scoped_picker(ui, Some(picker), add_contents);
+}
+
+/// Renders `add_contents` without the override of an enclosing [`with_picker`].
+pub(crate) fn without_picker(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui)) {
+    scoped_picker(ui, None, add_contents);
+}
+
+fn scoped_picker(ui: &mut Ui, picker: Option<Picker>, add_contents: impl FnOnce(&mut Ui)) {
+    let previous = ui.data_mut(|data| data.remove_temp::<Picker>(picker_id()));
+    if let Some(picker) = picker {
+        ui.data_mut(|data| data.insert_temp(picker_id(), picker));
+    }
+    add_contents(ui);
+    ui.data_mut(|data| match previous {
+        Some(previous) => {
+            data.insert_temp(picker_id(), previous);
+        }
+        None => data.remove::<Picker>(picker_id()),
+    });
+}
+
+/// The picker a derived enum should use: the override from an enclosing
+/// [`with_picker`] if there is one, or else the enum's own `default`.
+pub fn current_picker(ui: &Ui, default: Picker) -> Picker {
+    ui.data(|data| data.get_temp::<Picker>(picker_id()))
+        .unwrap_or(default)
+}
+
+/// A variant of a derived enum as listed in its picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantChoice {
+    pub label: &'static str,
+    /// False for `#[enum2egui(skip)]` variants, which can't be picked.
+    /// They are only listed, disabled, while active, so that the picker shows them.
+    pub selectable: bool,
+    /// Set with `#[enum2egui(group = "...")]`. Grouped variants are listed
+    /// after the ungrouped ones, under a heading per group.
+    pub group: Option<&'static str>,
+    /// The variant's doc comment, or `#[enum2egui(tooltip = "...")]`, shown on hover.
+    pub tooltip: Option<&'static str>,
+    /// Set for enums marked `#[enum2egui(show_discriminant)]`, and shown after the label.
+    pub discriminant: Option<i128>,
+}
+
+impl Default for VariantChoice {
+    fn default() -> Self {
+        Self {
+            label: "",
+            selectable: true,
+            group: None,
+            tooltip: None,
+            discriminant: None,
+        }
+    }
+}
+
+impl VariantChoice {
+    fn text(&self) -> String {
+        match self.discriminant {
+            Some(discriminant) => format!("{} (= {discriminant})", self.label),
+            None => self.label.to_owned(),
+        }
+    }
+}
+
+/// The variants to list under each group heading, in order of first appearance,
+/// with the ungrouped variants first and without a heading.
+/// Variants that can't be picked are left out unless `selected`.
+fn sections(
+    choices: &[VariantChoice],
+    selected: Option<usize>,
+) -> Vec<(Option<&'static str>, Vec<usize>)> {
+    let mut sections: Vec<(Option<&'static str>, Vec<usize>)> = vec![(None, Vec::new())];
+    let listed = choices
+        .iter()
+        .enumerate()
+        .filter(|(index, choice)| choice.selectable || selected == Some(*index));
+    listed.for_each(|(index, choice)| {
+        match sections
+            .iter_mut()
+            .find(|(group, _)| *group == choice.group)
+        {
+            Some((_, indices)) => indices.push(index),
+            None => sections.push((choice.group, vec![index])),
+        }
+    });
+    sections.retain(|(_, indices)| !indices.is_empty());
+    sections
+}
+
+/// Variant selector shared by derived enums.
+///
+/// `choices` are the listed variants and `selected` is the index of the active one,
+/// if it is listed. Returns the index of a newly picked variant.
+pub fn variant_picker_ui(
+    ui: &mut Ui,
+    picker: Picker,
+    selected_text: impl Into<egui::WidgetText>,
+    choices: &[VariantChoice],
+    selected: Option<usize>,
+) -> Option<usize> {
+    let mut picked = None;
+    let mut choice_ui = |ui: &mut Ui, index: usize| {
+        let choice = &choices[index];
+        let is_selected = selected == Some(index);
+        let mut response = if picker == Picker::Radio {
+            ui.add_enabled(
+                choice.selectable,
+                egui::RadioButton::new(is_selected, choice.text()),
+            )
+        } else {
+            ui.add_enabled(
+                choice.selectable,
+                egui::Button::selectable(is_selected, choice.text()),
+            )
+        };
+        if let Some(tooltip) = choice.tooltip {
+            response = response
+                .on_hover_text(tooltip)
+                .on_disabled_hover_text(tooltip);
+        }
+        if response.clicked() && !is_selected {
+            picked = Some(index);
+        }
+    };
+
+    let mut list_ui = |ui: &mut Ui, filter: &str| {
+        let needle = filter.to_lowercase();
+        sections(choices, selected)
+            .into_iter()
+            .for_each(|(group, indices)| {
+                let indices = indices
+                    .into_iter()
+                    .filter(|index| choices[*index].text().to_lowercase().contains(&needle))
+                    .collect::<Vec<_>>();
+                if indices.is_empty() {
+                    return;
+                }
+                if let Some(group) = group {
+                    ui.separator();
+                    ui.weak(group);
+                }
+                indices.into_iter().for_each(|index| choice_ui(ui, index));
+            });
+    };
+
+    match picker {
+        Picker::Combo => {
+            egui::ComboBox::from_id_salt(ui.next_auto_id())
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| list_ui(ui, ""));
+        }
+        Picker::Radio | Picker::Tabs => {
+            ui.vertical(|ui| {
+                sections(choices, selected)
+                    .into_iter()
+                    .for_each(|(group, indices)| {
+                        ui.horizontal_wrapped(|ui| {
+                            if let Some(group) = group {
+                                ui.weak(group);
+                            }
+                            indices.into_iter().for_each(|index| choice_ui(ui, index));
+                        });
+                    });
+            });
+        }
+        Picker::Search => {
+            let id = ui.next_auto_id();
+            let filter_id = id.with("filter");
+            // The filter is only kept while the popup is open, so its absence marks the first frame.
+            let stored = ui.data(|data| data.get_temp::<String>(filter_id));
+            let just_opened = stored.is_none();
+            let mut filter = stored.unwrap_or_default();
+
+            let response = egui::ComboBox::from_id_salt(id)
+                .selected_text(selected_text)
+                .show_ui(ui, |ui| {
+                    let search =
+                        ui.add(egui::TextEdit::singleline(&mut filter).hint_text("Search"));
+                    if just_opened {
+                        search.request_focus();
+                    }
+                    ui.separator();
+                    list_ui(ui, &filter);
+                });
+
+            if response.inner.is_some() && picked.is_none() {
+                ui.data_mut(|data| data.insert_temp(filter_id, filter));
+            } else {
+                ui.data_mut(|data| data.remove::<String>(filter_id));
+            }
+        }
+    }
+
+    picked
+}
+
+/// Numeric entry for the discriminant of an enum marked `#[enum2egui(show_discriminant)]`.
+/// Values that don't belong to a selectable variant are rejected.
+/// Returns the index of the variant whose discriminant was entered.
+pub fn discriminant_ui_mut(ui: &mut Ui, choices: &[VariantChoice], current: i128) -> Option<usize> {
+    let mut discriminant = current;
+    parse_with_ui_mut(&mut discriminant, ui, i128::to_string, |text| {
+        parse_discriminant(text, choices).map(|(discriminant, _)| discriminant)
+    });
+
+    if discriminant == current {
+        return None;
+    }
+    position_of_discriminant(choices, discriminant)
+}
+
+/// Parses an entered discriminant, along with the index of the selectable variant it belongs to.
+fn parse_discriminant(text: &str, choices: &[VariantChoice]) -> Result<(i128, usize), String> {
+    let discriminant = text
+        .trim()
+        .parse::<i128>()
+        .map_err(|error| error.to_string())?;
+    position_of_discriminant(choices, discriminant)
+        .map(|index| (discriminant, index))
+        .ok_or_else(|| format!("No variant has the discriminant {discriminant}"))
+}
+
+fn position_of_discriminant(choices: &[VariantChoice], discriminant: i128) -> Option<usize> {
+    choices
+        .iter()
+        .position(|choice| choice.selectable && choice.discriminant == Some(discriminant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choice(label: &'static str, group: Option<&'static str>) -> VariantChoice {
+        VariantChoice {
+            label,
+            group,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn sections_list_ungrouped_choices_first() {
+        let choices = [
+            choice("Red", Some("Warm")),
+            choice("Black", None),
+            choice("Blue", Some("Cool")),
+            choice("Orange", Some("Warm")),
+            choice("White", None),
+        ];
+        assert_eq!(
+            sections(&choices, None),
+            [
+                (None, vec![1, 4]),
+                (Some("Warm"), vec![0, 3]),
+                (Some("Cool"), vec![2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn sections_without_ungrouped_choices_have_no_empty_section() {
+        let choices = [choice("Red", Some("Warm")), choice("Blue", Some("Cool"))];
+        assert_eq!(
+            sections(&choices, None),
+            [(Some("Warm"), vec![0]), (Some("Cool"), vec![1])]
+        );
+    }
+
+    #[test]
+    fn sections_list_unselectable_choices_only_while_selected() {
+        let choices = [
+            choice("Red", None),
+            VariantChoice {
+                selectable: false,
+                ..choice("Legacy", None)
+            },
+        ];
+        assert_eq!(sections(&choices, Some(0)), [(None, vec![0])]);
+        assert_eq!(sections(&choices, Some(1)), [(None, vec![0, 1])]);
+    }
+
+    #[test]
+    fn discriminants_must_belong_to_a_selectable_variant() {
+        let choices = [
+            VariantChoice {
+                discriminant: Some(1),
+                ..choice("One", None)
+            },
+            VariantChoice {
+                discriminant: Some(2),
+                selectable: false,
+                ..choice("Two", None)
+            },
+            VariantChoice {
+                discriminant: Some(-3),
+                ..choice("MinusThree", None)
+            },
+        ];
+        assert_eq!(parse_discriminant(" -3 ", &choices), Ok((-3, 2)));
+        assert_eq!(parse_discriminant("1", &choices), Ok((1, 0)));
+        assert!(parse_discriminant("2", &choices).is_err());
+        assert!(parse_discriminant("4", &choices).is_err());
+        assert!(parse_discriminant("one", &choices).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+use crate::GuiInspect;
+use egui::Ui;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::DeserializeOwned};
+use serde_json::Value;
+
+/// Read-only view of any `Serialize` value, rendered as its serialized tree.
+pub fn serde_ui<T: Serialize>(value: &T, ui: &mut Ui) {
+    match serde_json::to_value(value) {
+        Ok(tree) => tree.ui(ui),
+        Err(error) => {
+            ui.colored_label(ui.visuals().error_fg_color, error.to_string());
+        }
+    }
+}
+
+/// Editor for any `Serialize + DeserializeOwned` value.
+///
+/// The value is serialized into a tree that is edited in place.
+/// Edits are deserialized back into `T` as they are made; while the tree
+/// doesn't deserialize, it is kept in memory and the error is shown below it.
+pub fn serde_ui_mut<T: Serialize + DeserializeOwned>(value: &mut T, ui: &mut Ui) {
+    let id = ui.next_auto_id();
+    let pending = ui.data(|data| data.get_temp::<(Value, String)>(id));
+    let (mut tree, error) = match pending {
+        Some((tree, error)) => (tree, Some(error)),
+        None => match serde_json::to_value(&*value) {
+            Ok(tree) => (tree, None),
+            Err(error) => {
+                ui.colored_label(ui.visuals().error_fg_color, error.to_string());
+                return;
+            }
+        },
+    };
+
+    ui.vertical(|ui| {
+        let original = tree.clone();
+        tree.ui_mut(ui);
+
+        let mut revert = false;
+        if let Some(error) = &error {
+            ui.horizontal(|ui| {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+                revert = ui.button("Revert").clicked();
+            });
+        }
+
+        if revert {
+            ui.data_mut(|data| data.remove::<(Value, String)>(id));
+        } else if tree != original || error.is_some() {
+            match T::deserialize(&tree) {
+                Ok(deserialized) => {
+                    *value = deserialized;
+                    ui.data_mut(|data| data.remove::<(Value, String)>(id));
+                }
+                Err(error) => {
+                    ui.data_mut(|data| data.insert_temp(id, (tree, error.to_string())));
+                }
+            }
+        }
+    });
+}
+
+/// Adapter that edits any serde type with [`serde_ui_mut`],
+/// for foreign types that can't derive `Gui` but implement `Serialize` and `Deserialize`.
+/// It serializes and deserializes transparently as the wrapped value.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SerdeInspect<T>(pub T);
+
+impl<T> From<T> for SerdeInspect<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> std::ops::Deref for SerdeInspect<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for SerdeInspect<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Serialize> Serialize for SerdeInspect<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SerdeInspect<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self)
+    }
+}
+
+impl<T: Serialize + DeserializeOwned> GuiInspect for SerdeInspect<T> {
+    fn ui(&self, ui: &mut Ui) {
+        serde_ui(&self.0, ui);
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        serde_ui_mut(&mut self.0, ui);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_as_the_wrapped_value() {
+        let wrapped = SerdeInspect((1, "one".to_owned()));
+        assert_eq!(serde_json::to_value(&wrapped).unwrap(), json!([1, "one"]));
+    }
+
+    #[test]
+    fn deserializes_as_the_wrapped_value() {
+        let wrapped: SerdeInspect<(u8, String)> =
+            serde_json::from_value(json!([1, "one"])).unwrap();
+        assert_eq!(wrapped, SerdeInspect((1, "one".to_owned())));
+        assert!(serde_json::from_value::<SerdeInspect<u8>>(json!("one")).is_err());
+    }
+}
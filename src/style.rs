@@ -0,0 +1,191 @@
+use crate::GuiInspect;
+use egui::{
+    Align, FontFamily, FontId, Margin, Pos2, Rect, Shadow, Stroke, Style, Ui, Vec2, Visuals,
+    epaint::CornerRadius,
+    style::{Selection, Spacing, WidgetVisuals, Widgets},
+};
+
+impl_gui_for_inline!(
+    Vec2 => { x, y }
+    Pos2 => { x, y }
+    Margin => { left, right, top, bottom }
+    CornerRadius => { nw, ne, sw, se }
+    Stroke => { width, color }
+);
+
+/// Implements `GuiInspect` for egui types with many fields,
+/// matching the layout of a derived struct.
+macro_rules! impl_gui_for_fields {
+    ($($t:ident => { $($field:ident),* $(,)? })*) => ($(
+        impl GuiInspect for $t {
+            fn ui(&self, ui: &mut Ui) {
+                fields_ui(ui, stringify!($t), |ui| {
+                    $(
+                        field_ui(ui, stringify!($field), |ui| self.$field.ui(ui));
+                    )*
+                });
+            }
+
+            fn ui_mut(&mut self, ui: &mut Ui) {
+                fields_ui(ui, stringify!($t), |ui| {
+                    $(
+                        field_ui(ui, stringify!($field), |ui| self.$field.ui_mut(ui));
+                    )*
+                });
+            }
+        }
+    )*)
+}
+
+fn fields_ui(ui: &mut Ui, name: &str, add_fields: impl FnOnce(&mut Ui)) {
+    ui.group(|ui| {
+        ui.horizontal(|ui| {
+            ui.label(name);
+            ui.vertical(add_fields);
+        });
+    });
+}
+
+fn field_ui(ui: &mut Ui, name: &str, add_value: impl FnOnce(&mut Ui)) {
+    ui.horizontal(|ui| {
+        ui.label(name);
+        add_value(ui);
+    });
+}
+
+impl_gui_for_fields!(
+    Rect => { min, max }
+    Shadow => { offset, blur, spread, color }
+    FontId => { size, family }
+    Selection => { bg_fill, stroke }
+    WidgetVisuals => { bg_fill, weak_bg_fill, bg_stroke, corner_radius, fg_stroke, expansion }
+    Widgets => { noninteractive, inactive, hovered, active, open }
+    Visuals => {
+        dark_mode,
+        override_text_color,
+        weak_text_alpha,
+        widgets,
+        selection,
+        hyperlink_color,
+        faint_bg_color,
+        extreme_bg_color,
+        code_bg_color,
+        warn_fg_color,
+        error_fg_color,
+        window_corner_radius,
+        window_shadow,
+        window_fill,
+        window_stroke,
+        window_highlight_topmost,
+        menu_corner_radius,
+        panel_fill,
+        popup_shadow,
+        resize_corner_size,
+        clip_rect_margin,
+        button_frame,
+        collapsing_header_frame,
+        indent_has_left_vline,
+        striped,
+        slider_trailing_fill,
+        disabled_alpha,
+    }
+    Spacing => {
+        item_spacing,
+        window_margin,
+        button_padding,
+        menu_margin,
+        indent,
+        interact_size,
+        slider_width,
+        slider_rail_height,
+        combo_width,
+        combo_height,
+        text_edit_width,
+        icon_width,
+        icon_width_inner,
+        icon_spacing,
+        default_area_size,
+        tooltip_width,
+        menu_width,
+        menu_spacing,
+        indent_ends_with_horizontal_line,
+    }
+);
+
+impl GuiInspect for Style {
+    fn ui(&self, ui: &mut Ui) {
+        fields_ui(ui, "Style", |ui| {
+            self.text_styles.iter().for_each(|(text_style, font_id)| {
+                field_ui(ui, &text_style.to_string(), |ui| font_id.ui(ui));
+            });
+            field_ui(ui, "spacing", |ui| self.spacing.ui(ui));
+            field_ui(ui, "visuals", |ui| self.visuals.ui(ui));
+            field_ui(ui, "animation_time", |ui| self.animation_time.ui(ui));
+            field_ui(ui, "explanation_tooltips", |ui| {
+                self.explanation_tooltips.ui(ui)
+            });
+            field_ui(ui, "url_in_tooltip", |ui| self.url_in_tooltip.ui(ui));
+            field_ui(ui, "compact_menu_style", |ui| {
+                self.compact_menu_style.ui(ui)
+            });
+        });
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        fields_ui(ui, "Style", |ui| {
+            self.text_styles
+                .iter_mut()
+                .for_each(|(text_style, font_id)| {
+                    field_ui(ui, &text_style.to_string(), |ui| font_id.ui_mut(ui));
+                });
+            field_ui(ui, "spacing", |ui| self.spacing.ui_mut(ui));
+            field_ui(ui, "visuals", |ui| self.visuals.ui_mut(ui));
+            field_ui(ui, "animation_time", |ui| self.animation_time.ui_mut(ui));
+            field_ui(ui, "explanation_tooltips", |ui| {
+                self.explanation_tooltips.ui_mut(ui)
+            });
+            field_ui(ui, "url_in_tooltip", |ui| self.url_in_tooltip.ui_mut(ui));
+            field_ui(ui, "compact_menu_style", |ui| {
+                self.compact_menu_style.ui_mut(ui)
+            });
+        });
+    }
+}
+
+/// Inspector for the context's global [`Style`], applying edits immediately.
+pub fn global_style_ui_mut(ui: &mut Ui) {
+    let mut style = (*ui.ctx().global_style()).clone();
+    style.ui_mut(ui);
+    if style != *ui.ctx().global_style() {
+        ui.ctx().set_global_style(style);
+    }
+}
+
+impl GuiInspect for FontFamily {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(self.to_string());
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        egui::ComboBox::from_id_salt(ui.next_auto_id())
+            .selected_text(self.to_string())
+            .show_ui(ui, |ui| {
+                ui.selectable_value(self, FontFamily::Proportional, "Proportional");
+                ui.selectable_value(self, FontFamily::Monospace, "Monospace");
+            });
+    }
+}
+
+impl GuiInspect for Align {
+    fn ui(&self, ui: &mut Ui) {
+        ui.label(format!("{self:?}"));
+    }
+
+    fn ui_mut(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(self, Align::Min, "Min");
+            ui.selectable_value(self, Align::Center, "Center");
+            ui.selectable_value(self, Align::Max, "Max");
+        });
+    }
+}
@@ -0,0 +1,598 @@
+use crate::{GuiInspect, nested_ui, sequence_ui};
+use egui::Ui;
+
+/// A node of a schemaless document whose type can be switched from a combo box.
+trait Tree: GuiInspect + Sized {
+    /// The names of the node types, in the order they appear in the combo box.
+    const KINDS: &'static [&'static str];
+
+    fn kind(&self) -> &'static str;
+
+    /// An empty node of the given type.
+    fn of_kind(kind: &str) -> Self;
+}
+
+/// The object type of a schemaless document.
+/// Keys are edited as text, which is validated by `check_key` before being committed.
+trait Object {
+    type Value: Tree;
+
+    fn keys(&self) -> Vec<String>;
+    fn get_mut(&mut self, key: &str) -> Option<&mut Self::Value>;
+    fn remove(&mut self, key: &str) -> Option<Self::Value>;
+    fn insert(&mut self, key: String, value: Self::Value);
+    fn check_key(&self, key: &str) -> Result<(), String>;
+}
+
+fn kind_ui_mut<T: Tree>(value: &mut T, ui: &mut Ui) {
+    let current = value.kind();
+    egui::ComboBox::from_id_salt(ui.next_auto_id())
+        .selected_text(current)
+        .show_ui(ui, |ui| {
+            T::KINDS.iter().for_each(|kind| {
+                if ui.selectable_label(*kind == current, *kind).clicked() && *kind != current {
+                    *value = T::of_kind(kind);
+                }
+            });
+        });
+}
+
+fn array_ui<T: GuiInspect>(items: &[T], ui: &mut Ui) {
+    nested_ui(ui, |ui| {
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                if items.is_empty() {
+                    ui.label("Empty array");
+                }
+                items.iter().for_each(|item| item.ui(ui));
+            });
+        });
+    });
+}
+
+fn object_ui<'a, T: GuiInspect + 'a>(entries: impl Iterator<Item = (String, &'a T)>, ui: &mut Ui) {
+    nested_ui(ui, |ui| {
+        ui.group(|ui| {
+            ui.vertical(|ui| {
+                let mut empty = true;
+                entries.for_each(|(key, value)| {
+                    empty = false;
+                    ui.horizontal(|ui| {
+                        ui.label(key);
+                        value.ui(ui);
+                    });
+                });
+                if empty {
+                    ui.label("Empty object");
+                }
+            });
+        });
+    });
+}
+
+enum ArrayAction {
+    MoveUp(usize),
+    MoveDown(usize),
+    Remove(usize),
+}
+
+/// Array editor with buttons to reorder and remove each element.
+/// New elements start out as the first node type.
+fn array_ui_mut<T: Tree>(items: &mut Vec<T>, ui: &mut Ui) {
+    nested_ui(ui, |ui| {
+        let len = items.len();
+        let mut add = false;
+        let mut action = None;
+
+        sequence_ui(
+            ui,
+            |ui| {
+                add = ui.button("Add").clicked();
+            },
+            |ui| {
+                items.iter_mut().enumerate().for_each(|(index, item)| {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(index > 0, egui::Button::new("⏶")).clicked() {
+                            action = Some(ArrayAction::MoveUp(index));
+                        }
+                        if ui
+                            .add_enabled(index + 1 < len, egui::Button::new("⏷"))
+                            .clicked()
+                        {
+                            action = Some(ArrayAction::MoveDown(index));
+                        }
+                        if ui.button("🗑").clicked() {
+                            action = Some(ArrayAction::Remove(index));
+                        }
+                        item.ui_mut(ui);
+                    });
+                });
+            },
+        );
+
+        match action {
+            Some(ArrayAction::MoveUp(index)) => items.swap(index - 1, index),
+            Some(ArrayAction::MoveDown(index)) => items.swap(index, index + 1),
+            Some(ArrayAction::Remove(index)) => {
+                items.remove(index);
+            }
+            None => {}
+        }
+        if add {
+            items.push(T::of_kind(T::KINDS[0]));
+        }
+    });
+}
+
+enum ObjectAction {
+    Rename(String, String),
+    Remove(String),
+}
+
+/// Object editor with an editable key and a remove button for each entry.
+/// New entries start out as the first node type.
+fn object_ui_mut<M: Object>(map: &mut M, ui: &mut Ui) {
+    nested_ui(ui, |ui| {
+        let pending_id = ui.next_auto_id().with("pending");
+        let mut pending = ui
+            .data(|data| data.get_temp::<String>(pending_id))
+            .unwrap_or_default();
+        let pending_error = map.check_key(&pending).err();
+        let mut add = false;
+        let mut action = None;
+
+        sequence_ui(
+            ui,
+            |ui| {
+                ui.add(egui::TextEdit::singleline(&mut pending).hint_text("Key"));
+                let response = ui.add_enabled(pending_error.is_none(), egui::Button::new("Add"));
+                if let Some(error) = pending_error {
+                    response.on_disabled_hover_text(error);
+                } else {
+                    add = response.clicked();
+                }
+            },
+            |ui| {
+                map.keys().into_iter().for_each(|key| {
+                    ui.horizontal(|ui| {
+                        if ui.button("🗑").clicked() {
+                            action = Some(ObjectAction::Remove(key.clone()));
+                        }
+                        if let Some(renamed) = key_ui_mut(&key, ui, |text| map.check_key(text)) {
+                            action = Some(ObjectAction::Rename(key.clone(), renamed));
+                        }
+                        if let Some(value) = map.get_mut(&key) {
+                            value.ui_mut(ui);
+                        }
+                    });
+                });
+            },
+        );
+
+        match action {
+            Some(ObjectAction::Rename(from, to)) => {
+                if let Some(value) = map.remove(&from) {
+                    map.insert(to, value);
+                }
+            }
+            Some(ObjectAction::Remove(key)) => {
+                map.remove(&key);
+            }
+            None => {}
+        }
+        if add && map.check_key(&pending).is_ok() {
+            let kind = <M::Value as Tree>::KINDS[0];
+            map.insert(std::mem::take(&mut pending), M::Value::of_kind(kind));
+        }
+        ui.data_mut(|data| data.insert_temp(pending_id, pending));
+    });
+}
+
+/// Text box for an object key that only renames the entry once editing finishes,
+/// so the entry doesn't move around in a sorted map while the key is being typed.
+fn key_ui_mut(
+    key: &str,
+    ui: &mut Ui,
+    check_key: impl Fn(&str) -> Result<(), String>,
+) -> Option<String> {
+    let id = ui.next_auto_id();
+    let mut text = ui
+        .data(|data| data.get_temp::<String>(id))
+        .unwrap_or_else(|| key.to_owned());
+
+    let error = (text != key).then(|| check_key(&text).err()).flatten();
+    let text_color = error.as_ref().map(|_| ui.visuals().error_fg_color);
+
+    let mut response = ui.add(
+        egui::TextEdit::singleline(&mut text)
+            .id(id)
+            .desired_width(ui.spacing().text_edit_width / 2.0)
+            .text_color_opt(text_color),
+    );
+    if let Some(error) = &error {
+        response = response.on_hover_text(error);
+    }
+
+    if response.has_focus() {
+        ui.data_mut(|data| data.insert_temp(id, text));
+        None
+    } else {
+        ui.data_mut(|data| data.remove::<String>(id));
+        (response.lost_focus() && text != key && error.is_none()).then_some(text)
+    }
+}
+
+fn duplicate_key_error() -> String {
+    "Key already exists".to_owned()
+}
+
+/// Implements `Object` for maps keyed by `String` with the `serde_json` map API.
+#[cfg(any(feature = "serde_json", feature = "toml"))]
+macro_rules! impl_object_for_string_map {
+    ($($map:ty => $value:ty)*) => ($(
+        impl Object for $map {
+            type Value = $value;
+
+            fn keys(&self) -> Vec<String> {
+                self.keys().cloned().collect()
+            }
+
+            fn get_mut(&mut self, key: &str) -> Option<&mut Self::Value> {
+                self.get_mut(key)
+            }
+
+            fn remove(&mut self, key: &str) -> Option<Self::Value> {
+                self.remove(key)
+            }
+
+            fn insert(&mut self, key: String, value: Self::Value) {
+                self.insert(key, value);
+            }
+
+            fn check_key(&self, key: &str) -> Result<(), String> {
+                if self.contains_key(key) {
+                    Err(super::duplicate_key_error())
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    )*)
+}
+
+#[cfg(feature = "serde_json")]
+mod json {
+    use super::{Object, Tree, array_ui, array_ui_mut, kind_ui_mut, object_ui, object_ui_mut};
+    use crate::{GuiInspect, display_ui, parse_ui_mut};
+    use egui::Ui;
+    use serde_json::{Map, Value};
+
+    impl_object_for_string_map!(Map<String, Value> => Value);
+
+    impl Tree for Value {
+        const KINDS: &'static [&'static str] =
+            &["Null", "Bool", "Number", "String", "Array", "Object"];
+
+        fn kind(&self) -> &'static str {
+            match self {
+                Value::Null => "Null",
+                Value::Bool(_) => "Bool",
+                Value::Number(_) => "Number",
+                Value::String(_) => "String",
+                Value::Array(_) => "Array",
+                Value::Object(_) => "Object",
+            }
+        }
+
+        fn of_kind(kind: &str) -> Self {
+            match kind {
+                "Bool" => Value::Bool(false),
+                "Number" => Value::from(0),
+                "String" => Value::String(String::new()),
+                "Array" => Value::Array(Vec::new()),
+                "Object" => Value::Object(Map::new()),
+                _ => Value::Null,
+            }
+        }
+    }
+
+    impl GuiInspect for Value {
+        fn ui(&self, ui: &mut Ui) {
+            match self {
+                Value::Null => {
+                    ui.weak("null");
+                }
+                Value::Bool(value) => value.ui(ui),
+                Value::Number(value) => display_ui(value, ui),
+                Value::String(value) => value.ui(ui),
+                Value::Array(items) => array_ui(items, ui),
+                Value::Object(map) => {
+                    object_ui(map.iter().map(|(key, value)| (key.clone(), value)), ui)
+                }
+            }
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                kind_ui_mut(self, ui);
+                match self {
+                    Value::Null => {}
+                    Value::Bool(value) => value.ui_mut(ui),
+                    Value::Number(value) => parse_ui_mut(value, ui),
+                    Value::String(value) => value.ui_mut(ui),
+                    Value::Array(items) => array_ui_mut(items, ui),
+                    Value::Object(map) => object_ui_mut(map, ui),
+                }
+            });
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+mod toml_impls {
+    use super::{Object, Tree, array_ui, array_ui_mut, kind_ui_mut, object_ui, object_ui_mut};
+    use crate::{GuiInspect, display_ui, parse_ui_mut};
+    use egui::Ui;
+    use toml::{Table, Value};
+
+    impl_object_for_string_map!(Table => Value);
+
+    impl Tree for Value {
+        const KINDS: &'static [&'static str] = &[
+            "String", "Integer", "Float", "Boolean", "Datetime", "Array", "Table",
+        ];
+
+        fn kind(&self) -> &'static str {
+            match self {
+                Value::String(_) => "String",
+                Value::Integer(_) => "Integer",
+                Value::Float(_) => "Float",
+                Value::Boolean(_) => "Boolean",
+                Value::Datetime(_) => "Datetime",
+                Value::Array(_) => "Array",
+                Value::Table(_) => "Table",
+            }
+        }
+
+        fn of_kind(kind: &str) -> Self {
+            match kind {
+                "Integer" => Value::Integer(0),
+                "Float" => Value::Float(0.0),
+                "Boolean" => Value::Boolean(false),
+                "Datetime" => Value::Datetime(toml::value::Datetime {
+                    date: Some(toml::value::Date {
+                        year: 1970,
+                        month: 1,
+                        day: 1,
+                    }),
+                    time: None,
+                    offset: None,
+                }),
+                "Array" => Value::Array(Vec::new()),
+                "Table" => Value::Table(Table::new()),
+                _ => Value::String(String::new()),
+            }
+        }
+    }
+
+    impl GuiInspect for Value {
+        fn ui(&self, ui: &mut Ui) {
+            match self {
+                Value::String(value) => value.ui(ui),
+                Value::Integer(value) => value.ui(ui),
+                Value::Float(value) => value.ui(ui),
+                Value::Boolean(value) => value.ui(ui),
+                Value::Datetime(value) => display_ui(value, ui),
+                Value::Array(items) => array_ui(items, ui),
+                Value::Table(table) => {
+                    object_ui(table.iter().map(|(key, value)| (key.clone(), value)), ui)
+                }
+            }
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                kind_ui_mut(self, ui);
+                match self {
+                    Value::String(value) => value.ui_mut(ui),
+                    Value::Integer(value) => value.ui_mut(ui),
+                    Value::Float(value) => value.ui_mut(ui),
+                    Value::Boolean(value) => value.ui_mut(ui),
+                    Value::Datetime(value) => parse_ui_mut(value, ui),
+                    Value::Array(items) => array_ui_mut(items, ui),
+                    Value::Table(table) => object_ui_mut(table, ui),
+                }
+            });
+        }
+    }
+}
+
+#[cfg(feature = "ron")]
+mod ron_impls {
+    use super::{
+        Object, Tree, array_ui, array_ui_mut, duplicate_key_error, kind_ui_mut, object_ui,
+        object_ui_mut,
+    };
+    use crate::{GuiInspect, parse_with_ui_mut};
+    use egui::Ui;
+    use ron::{Map, Number, Value};
+
+    /// RON map keys can be any value, so they are edited as RON text.
+    fn key_text(key: &Value) -> String {
+        ron::to_string(key).unwrap_or_default()
+    }
+
+    fn parse_key(text: &str) -> Result<Value, String> {
+        ron::from_str(text).map_err(|error| error.to_string())
+    }
+
+    impl Object for Map {
+        type Value = Value;
+
+        fn keys(&self) -> Vec<String> {
+            self.keys().map(key_text).collect()
+        }
+
+        fn get_mut(&mut self, key: &str) -> Option<&mut Value> {
+            self.get_mut(&parse_key(key).ok()?)
+        }
+
+        fn remove(&mut self, key: &str) -> Option<Value> {
+            self.remove(&parse_key(key).ok()?)
+        }
+
+        fn insert(&mut self, key: String, value: Value) {
+            if let Ok(key) = parse_key(&key) {
+                self.insert(key, value);
+            }
+        }
+
+        fn check_key(&self, key: &str) -> Result<(), String> {
+            if self.get(&parse_key(key)?).is_some() {
+                Err(duplicate_key_error())
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    fn number_text(number: &Number) -> String {
+        ron::to_string(number).unwrap_or_default()
+    }
+
+    fn parse_number(text: &str) -> Result<Number, String> {
+        match ron::from_str(text) {
+            Ok(Value::Number(number)) => Ok(number),
+            Ok(_) => Err("Not a number".to_owned()),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+
+    impl Tree for Value {
+        const KINDS: &'static [&'static str] = &[
+            "Unit", "Bool", "Char", "Number", "String", "Bytes", "Option", "Seq", "Map",
+        ];
+
+        fn kind(&self) -> &'static str {
+            match self {
+                Value::Unit => "Unit",
+                Value::Bool(_) => "Bool",
+                Value::Char(_) => "Char",
+                Value::Number(_) => "Number",
+                Value::String(_) => "String",
+                Value::Bytes(_) => "Bytes",
+                Value::Option(_) => "Option",
+                Value::Seq(_) => "Seq",
+                Value::Map(_) => "Map",
+            }
+        }
+
+        fn of_kind(kind: &str) -> Self {
+            match kind {
+                "Bool" => Value::Bool(false),
+                "Char" => Value::Char(' '),
+                "Number" => Value::Number(Number::from(0)),
+                "String" => Value::String(String::new()),
+                "Bytes" => Value::Bytes(Vec::new()),
+                "Option" => Value::Option(None),
+                "Seq" => Value::Seq(Vec::new()),
+                "Map" => Value::Map(Map::new()),
+                _ => Value::Unit,
+            }
+        }
+    }
+
+    impl GuiInspect for Value {
+        fn ui(&self, ui: &mut Ui) {
+            match self {
+                Value::Unit => {
+                    ui.weak("()");
+                }
+                Value::Bool(value) => value.ui(ui),
+                Value::Char(value) => value.ui(ui),
+                Value::Number(value) => {
+                    ui.label(number_text(value));
+                }
+                Value::String(value) => value.ui(ui),
+                Value::Bytes(bytes) => bytes.ui(ui),
+                Value::Option(None) => {
+                    ui.weak("None");
+                }
+                Value::Option(Some(value)) => value.ui(ui),
+                Value::Seq(items) => array_ui(items, ui),
+                Value::Map(map) => {
+                    object_ui(map.iter().map(|(key, value)| (key_text(key), value)), ui)
+                }
+            }
+        }
+
+        fn ui_mut(&mut self, ui: &mut Ui) {
+            ui.horizontal(|ui| {
+                kind_ui_mut(self, ui);
+                match self {
+                    Value::Unit => {}
+                    Value::Bool(value) => value.ui_mut(ui),
+                    Value::Char(value) => value.ui_mut(ui),
+                    Value::Number(value) => parse_with_ui_mut(value, ui, number_text, parse_number),
+                    Value::String(value) => value.ui_mut(ui),
+                    Value::Bytes(bytes) => bytes.ui_mut(ui),
+                    Value::Option(value) => {
+                        let mut is_some = value.is_some();
+                        if ui.checkbox(&mut is_some, "Some").changed() {
+                            *value = is_some.then(|| Box::new(Value::Unit));
+                        }
+                        if let Some(value) = value {
+                            value.ui_mut(ui);
+                        }
+                    }
+                    Value::Seq(items) => array_ui_mut(items, ui),
+                    Value::Map(map) => object_ui_mut(map, ui),
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Object, Tree};
+
+    /// Every kind in the combo box creates a node that reports that kind back.
+    fn assert_kinds_round_trip<T: Tree>() {
+        T::KINDS.iter().for_each(|kind| {
+            assert_eq!(T::of_kind(kind).kind(), *kind);
+        });
+    }
+
+    fn assert_keys_are_checked<M: Object>(mut map: M, key: &str, value: M::Value) {
+        assert_eq!(map.check_key(key), Ok(()));
+        map.insert(key.to_owned(), value);
+        assert_eq!(map.keys(), [key]);
+        assert!(map.check_key(key).is_err());
+        assert!(map.get_mut(key).is_some());
+        assert!(map.remove(key).is_some());
+        assert!(map.keys().is_empty());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn json_values() {
+        assert_kinds_round_trip::<serde_json::Value>();
+        assert_keys_are_checked(serde_json::Map::new(), "key", serde_json::Value::Null);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_values() {
+        assert_kinds_round_trip::<toml::Value>();
+        assert_keys_are_checked(toml::Table::new(), "key", toml::Value::Boolean(true));
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn ron_values() {
+        assert_kinds_round_trip::<ron::Value>();
+        assert_keys_are_checked(ron::Map::new(), "\"a\"", ron::Value::Unit);
+        assert!(ron::Map::new().check_key("(unclosed").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+#[test]
+fn invalid_attributes() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn valid_attributes() {
+    trybuild::TestCases::new().pass("tests/ui-pass/*.rs");
+}
@@ -0,0 +1,39 @@
+use enum2egui::{Gui, GuiInspect, egui};
+
+/// Doesn't implement `GuiInspect`.
+#[derive(Debug, Default, PartialEq)]
+struct Opaque(u8);
+
+#[derive(Gui, Debug, Default, PartialEq)]
+enum Mode {
+    #[default]
+    Idle,
+    #[enum2egui(skip)]
+    Legacy(Opaque),
+    #[enum2egui(skip)]
+    Named { value: Opaque },
+}
+
+impl std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+fn run(add_contents: impl FnMut(&mut egui::Ui)) {
+    let _ = egui::Context::default().run_ui(egui::RawInput::default(), add_contents);
+}
+
+#[test]
+fn skipped_variants_with_opaque_payloads_are_shown() {
+    let mut mode = Mode::Legacy(Opaque(7));
+    run(|ui| {
+        mode.ui(ui);
+        mode.ui_mut(ui);
+    });
+    assert_eq!(mode, Mode::Legacy(Opaque(7)));
+
+    let mut mode = Mode::Named { value: Opaque(7) };
+    run(|ui| mode.ui_mut(ui));
+    assert_eq!(mode, Mode::Named { value: Opaque(7) });
+}
@@ -0,0 +1,49 @@
+use enum2egui::{Gui, GuiInspect};
+use std::collections::HashSet;
+
+#[derive(Gui, Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+enum Permission {
+    #[default]
+    Read,
+    Write,
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(multiline)]
+    notes: std::string::String,
+    #[enum2egui(hint = "Name")]
+    name: ::std::string::String,
+    #[enum2egui(max_len = 4)]
+    items: alloc::vec::Vec<u8>,
+    #[enum2egui(option_style = "button")]
+    limit: core::option::Option<u8>,
+    #[enum2egui(flags)]
+    permissions: Vec<Permission>,
+    #[enum2egui(flags)]
+    granted: HashSet<Permission>,
+    #[enum2egui(color)]
+    rgb: [u8; 3],
+    #[enum2egui(color, alpha)]
+    rgba: [u8; 4],
+    #[enum2egui(color)]
+    linear: [f32; 3],
+    #[enum2egui(color, hsva)]
+    hsva: [f32; 4],
+    #[enum2egui(color)]
+    tuple: (u8, u8, u8),
+    #[enum2egui(color, alpha)]
+    tuple_alpha: (u8, u8, u8, u8),
+    #[enum2egui(color)]
+    packed: u32,
+}
+
+extern crate alloc;
+
+fn main() {}
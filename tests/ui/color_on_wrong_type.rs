@@ -0,0 +1,13 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(color)]
+    tint: [u8; 5],
+    #[enum2egui(color)]
+    shade: (u8, f32, u8),
+    #[enum2egui(color, alpha)]
+    packed: u64,
+}
+
+fn main() {}
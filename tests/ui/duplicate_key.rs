@@ -0,0 +1,9 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(label = "First", label = "Second")]
+    value: u32,
+}
+
+fn main() {}
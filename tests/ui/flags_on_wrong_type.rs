@@ -0,0 +1,9 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(flags)]
+    permissions: Option<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(min_len = 5, max_len = 2)]
+    items: Vec<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+#[enum2egui(picker = "radio")]
+struct Data {
+    value: u32,
+}
+
+fn main() {}
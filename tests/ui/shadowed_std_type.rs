@@ -0,0 +1,14 @@
+use enum2egui::Gui;
+
+mod my {
+    #[derive(Default)]
+    pub struct String;
+}
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(multiline)]
+    notes: my::String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+enum Mode {
+    #[default]
+    Idle,
+    #[enum2egui(skip, hidden)]
+    Legacy,
+}
+
+impl std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mode")
+    }
+}
+
+fn main() {}
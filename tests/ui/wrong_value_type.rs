@@ -0,0 +1,9 @@
+use enum2egui::Gui;
+
+#[derive(Gui, Default)]
+struct Data {
+    #[enum2egui(multiline, rows = "3")]
+    text: String,
+}
+
+fn main() {}